@@ -0,0 +1,72 @@
+use chrono::{Duration, NaiveDate};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yasumi::public_holiday::{substitute_holiday, CitizensHoliday, PublicHoliday, HOLIDAYS};
+
+/// 2024年の1年分の日付
+fn dates() -> Vec<NaiveDate> {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    (0..366).map(|days| start + Duration::days(days)).collect()
+}
+
+/// 祝日の表を使わずに, 日付ごとにすべての規則を評価する
+fn is_holiday_by_rules(date: &NaiveDate) -> bool {
+    HOLIDAYS.iter().any(|holiday| holiday.is_holiday(date))
+        || substitute_holiday(date).is_some()
+        || CitizensHoliday.is_holiday(date)
+}
+
+fn bench_is_holiday(c: &mut Criterion) {
+    let dates = dates();
+    let mut group = c.benchmark_group("is_holiday (366 days)");
+    group.bench_function("rules", |b| {
+        b.iter(|| {
+            dates
+                .iter()
+                .filter(|date| is_holiday_by_rules(date))
+                .count()
+        })
+    });
+    group.bench_function("year table", |b| {
+        b.iter(|| {
+            dates
+                .iter()
+                .filter(|date| yasumi::is_holiday(**date))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn bench_holiday_name(c: &mut Criterion) {
+    let dates = dates();
+    c.bench_function("holiday_name (366 days)", |b| {
+        b.iter(|| {
+            dates
+                .iter()
+                .filter_map(|date| yasumi::holiday_name(*date))
+                .count()
+        })
+    });
+}
+
+fn bench_year_holidays(c: &mut Criterion) {
+    c.bench_function("year_holidays", |b| {
+        b.iter(|| yasumi::year_holidays(black_box(2024)))
+    });
+}
+
+fn bench_add_business_days(c: &mut Criterion) {
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    c.bench_function("add_business_days (2500 days)", |b| {
+        b.iter(|| yasumi::add_business_days(black_box(date), 2500))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_is_holiday,
+    bench_holiday_name,
+    bench_year_holidays,
+    bench_add_business_days
+);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+//! `static-table` フィーチャーが有効な場合に, 対応範囲の祝日の表を生成します.
+//!
+//! 祝日の規則はライブラリと同じソースコードを読み込んで評価します.
+
+// ライブラリのモジュールのうち, 表の生成に使わない項目は未使用になる
+#[cfg(feature = "static-table")]
+#[path = "src/equinox.rs"]
+#[allow(dead_code)]
+mod equinox;
+#[cfg(feature = "static-table")]
+#[path = "src/holiday.rs"]
+#[allow(dead_code)]
+mod holiday;
+#[cfg(feature = "static-table")]
+#[path = "src/law.rs"]
+#[allow(dead_code)]
+mod law;
+#[cfg(feature = "static-table")]
+#[path = "src/public_holiday.rs"]
+#[allow(dead_code)]
+mod public_holiday;
+
+fn main() {
+    #[cfg(feature = "static-table")]
+    generate::run();
+}
+
+#[cfg(feature = "static-table")]
+mod generate {
+    use std::fmt::Write;
+    use std::path::Path;
+    use std::{env, fs};
+
+    use chrono::{Datelike, NaiveDate};
+
+    use crate::holiday::HolidayStatus;
+    use crate::law::{period_on, Period};
+    use crate::public_holiday::{
+        find_citizens_holiday, find_substitute_holiday, national_holiday_on, supported_years,
+        PublicHoliday, CITIZENS_HOLIDAY_PERIODS, HOLIDAYS, SUBSTITUTE_HOLIDAY_PERIODS,
+    };
+
+    pub(crate) fn run() {
+        for source in ["equinox", "holiday", "law", "public_holiday"] {
+            println!("cargo:rerun-if-changed=src/{source}.rs");
+        }
+        println!("cargo:rerun-if-changed=build.rs");
+
+        let mut offsets = vec![0];
+        let mut entries = String::new();
+        let mut count = 0;
+        for year in supported_years() {
+            let start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+            for date in start.iter_days().take_while(|date| date.year() == year) {
+                if let Some(entry) = entry(&date) {
+                    writeln!(entries, "    {entry},").unwrap();
+                    count += 1;
+                }
+            }
+            offsets.push(count);
+        }
+
+        let offsets: Vec<String> = offsets.iter().map(|offset| offset.to_string()).collect();
+        let code = format!(
+            "/// 表の最初の年\n\
+             const FIRST_YEAR: i32 = {};\n\n\
+             /// 年ごとの祝日の開始位置\n\
+             static YEAR_OFFSETS: [u16; {}] = [{}];\n\n\
+             /// 祝日(昇順)\n\
+             static ENTRIES: [Entry; {count}] = [\n{entries}];\n",
+            supported_years().start(),
+            offsets.len(),
+            offsets.join(", "),
+        );
+        let path = Path::new(&env::var("OUT_DIR").unwrap()).join("static_table.rs");
+        fs::write(path, code).unwrap();
+    }
+
+    /// 指定した日付の祝日の表の要素
+    fn entry(date: &NaiveDate) -> Option<String> {
+        if let Some(index) = HOLIDAYS.iter().position(|holiday| holiday.is_holiday(date)) {
+            let holiday = HOLIDAYS[index];
+            return Some(format_entry(
+                date,
+                &format!("Rule::Holiday({index})"),
+                holiday.status(date),
+                holiday.periods(),
+            ));
+        }
+
+        if let Some((substituted, status)) =
+            find_substitute_holiday(date, &NaiveDate::MAX, national_holiday_on)
+        {
+            let index = HOLIDAYS
+                .iter()
+                .position(|holiday| holiday.id() == substituted.id())
+                .unwrap();
+            return Some(format_entry(
+                date,
+                &format!("Rule::Substitute({index})"),
+                status,
+                SUBSTITUTE_HOLIDAY_PERIODS,
+            ));
+        }
+
+        let status = find_citizens_holiday(date, &NaiveDate::MAX, national_holiday_on)?;
+        Some(format_entry(
+            date,
+            "Rule::Citizens",
+            status,
+            CITIZENS_HOLIDAY_PERIODS,
+        ))
+    }
+
+    fn format_entry(
+        date: &NaiveDate,
+        rule: &str,
+        status: HolidayStatus,
+        periods: &[Period],
+    ) -> String {
+        let period = period_on(periods, date).unwrap();
+        let period = periods
+            .iter()
+            .position(|candidate| std::ptr::eq(candidate, period))
+            .unwrap();
+        format!(
+            "Entry {{ ordinal: {}, rule: {rule}, status: HolidayStatus::{status:?}, period: {period} }}",
+            date.ordinal()
+        )
+    }
+}
@@ -0,0 +1,156 @@
+//! 銀行休業日
+//!
+//! 銀行法第15条および銀行法施行令第5条により, 銀行は土日祝と年末年始(12月31日から1月3日まで)に休業します.
+//!
+//! 現行の休業日の規則をすべての年に適用します. 土曜日が休業日となった1989年2月より前など,
+//! 規則が異なる過去の年の休業日は正しくありません.
+
+use std::sync::OnceLock;
+
+use chrono::NaiveDate;
+
+use crate::calendar::HolidayCalendar;
+use crate::convention::BusinessDayConvention;
+use crate::datelike::DateLike;
+
+impl HolidayCalendar {
+    /// 銀行休業日のカレンダーを作成します.
+    ///
+    /// 国民の祝日に加えて, 12月31日, 1月2日, 1月3日を含みます.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let bank = HolidayCalendar::bank();
+    /// assert_eq!(bank.holiday_name("2025-01-03"), Some("年末年始休業日".to_string()));
+    /// ```
+    pub fn bank() -> HolidayCalendar {
+        HolidayCalendar::builder()
+            .fixed_dates(
+                "bank_year_end_holiday",
+                "年末年始休業日",
+                &[(12, 31), (1, 2), (1, 3)],
+            )
+            .build()
+    }
+}
+
+/// 銀行休業日のカレンダーを取得します.
+fn bank_calendar() -> &'static HolidayCalendar {
+    static BANK_CALENDAR: OnceLock<HolidayCalendar> = OnceLock::new();
+    BANK_CALENDAR.get_or_init(HolidayCalendar::bank)
+}
+
+/// 指定した日付が銀行休業日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 銀行休業日の場合は `true` を返します. それ以外の場合は `false` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::is_bank_holiday;
+///
+/// assert_eq!(is_bank_holiday("2024-12-31"), true);
+/// assert_eq!(is_bank_holiday("2024-12-30"), false);
+/// ```
+pub fn is_bank_holiday<T: DateLike>(date: T) -> bool {
+    bank_calendar().is_no_workday(date)
+}
+
+/// 指定した日付の翌銀行営業日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 翌銀行営業日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::next_bank_business_day;
+///
+/// let date = next_bank_business_day("2024-12-30");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 1, 6));
+/// ```
+pub fn next_bank_business_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    bank_calendar().next_business_day(date)
+}
+
+/// 指定した日付の前銀行営業日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 前銀行営業日を返します. 日付が不正な場合は `None` を返します.
+pub fn previous_bank_business_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    bank_calendar().previous_business_day(date)
+}
+
+/// 指定した取引日から指定した銀行営業日数後の資金決済日(T+N)を取得します.
+/// 取引日が銀行休業日の場合は, 翌銀行営業日を取引日とします.
+///
+/// # Arguments
+///
+/// * `trade_date` - 取引日
+/// * `days` - 銀行営業日数
+///
+/// # Returns
+///
+/// 資金決済日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::settlement_date;
+///
+/// // T+2
+/// let date = settlement_date("2024-12-27", 2);
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 1, 6));
+/// ```
+pub fn settlement_date<T: DateLike>(trade_date: T, days: u32) -> Option<NaiveDate> {
+    let calendar = bank_calendar();
+    let trade_date = calendar.adjust(trade_date, BusinessDayConvention::Following)?;
+    calendar.add_business_days(trade_date, days)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2024/12/30", false)]
+    #[case("2024/12/31", true)]
+    #[case("2025/01/01", true)]
+    #[case("2025/01/02", true)]
+    #[case("2025/01/03", true)]
+    #[case("2025/01/04", true)] // 土曜日
+    #[case("2025/01/06", false)]
+    #[case("2025/01/13", true)] // 成人の日
+    fn test_is_bank_holiday(#[case] date: &str, #[case] expected: bool) {
+        assert_eq!(is_bank_holiday(date), expected);
+    }
+
+    #[rstest]
+    #[case("2024/12/27", 0, "2024/12/27")]
+    #[case("2024/12/27", 1, "2024/12/30")]
+    #[case("2024/12/28", 0, "2024/12/30")] // 取引日が土曜日
+    #[case("2024/12/31", 1, "2025/01/07")] // 取引日が年末年始休業日
+    fn test_settlement_date(#[case] trade_date: &str, #[case] days: u32, #[case] expected: &str) {
+        let expected = NaiveDate::parse_from_str(expected, "%Y/%m/%d").unwrap();
+        assert_eq!(settlement_date(trade_date, days), Some(expected));
+    }
+}
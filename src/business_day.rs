@@ -0,0 +1,341 @@
+//! 営業日の計算
+//!
+//! 定休日(既定では土日)と祝日を除いた日を営業日として, 営業日単位で日付を移動します.
+//! 祝日は年単位でまとめて求めるため, 大きな営業日数でも日ごとに祝日を判定しません.
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::calendar::HolidayCalendar;
+use crate::datelike::DateLike;
+use crate::weekend::Weekend;
+
+/// 1年分の祝日の一覧
+struct YearHolidays {
+    year: i32,
+    weekend: Weekend,
+    /// 定休日を除いた祝日(昇順)
+    dates: Vec<NaiveDate>,
+}
+
+impl YearHolidays {
+    fn new(calendar: &HolidayCalendar, year: i32) -> YearHolidays {
+        let weekend = calendar.weekend();
+        let dates = calendar
+            .year_holidays_detail(year)
+            .into_iter()
+            .map(|holiday| holiday.date)
+            .filter(|date| !weekend.is_weekend(date))
+            .collect();
+        YearHolidays {
+            year,
+            weekend,
+            dates,
+        }
+    }
+
+    fn is_business_day(&self, date: &NaiveDate) -> bool {
+        !self.weekend.is_weekend(date) && self.dates.binary_search(date).is_err()
+    }
+
+    /// 指定した期間(両端を含む)の営業日数を数えます.
+    fn count(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if start > end {
+            return 0;
+        }
+        let holidays = self
+            .dates
+            .iter()
+            .filter(|date| (start..=end).contains(*date))
+            .count() as i64;
+        weekdays(&self.weekend, start, end) - holidays
+    }
+}
+
+/// 指定した期間(両端を含む)の定休日以外の日数を数えます.
+fn weekdays(weekend: &Weekend, start: NaiveDate, end: NaiveDate) -> i64 {
+    let days = (end - start).num_days() + 1;
+    let mut count = days / 7 * (7 - weekend.len() as i64);
+    let mut date = start + Duration::days(days / 7 * 7);
+    while date <= end {
+        if !weekend.is_weekend(&date) {
+            count += 1;
+        }
+        match date.succ_opt() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    count
+}
+
+impl HolidayCalendar {
+    /// 指定した日付が営業日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 営業日の場合は `true` を返します. それ以外の場合は `false` を返します.
+    pub fn is_business_day<T: DateLike>(&self, date: T) -> bool {
+        !self.is_no_workday(date)
+    }
+
+    /// 指定した日付から指定した営業日数後の日付を取得します.
+    /// 指定した日付は数えません.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `days` - 営業日数
+    ///
+    /// # Returns
+    ///
+    /// 営業日数後の日付を返します. 日付が不正な場合や営業日が存在しない場合は `None` を返します.
+    pub fn add_business_days<T: DateLike>(&self, date: T, days: u32) -> Option<NaiveDate> {
+        let mut date = date.date()?;
+        let mut remaining = days as i64;
+        // すべての曜日が定休日の場合は営業日が存在しない
+        if remaining > 0 && self.weekend().len() == 7 {
+            return None;
+        }
+
+        loop {
+            let holidays = YearHolidays::new(self, date.succ_opt()?.year());
+            let end_of_year = NaiveDate::from_ymd_opt(holidays.year, 12, 31)?;
+            let count = holidays.count(date.succ_opt()?, end_of_year);
+            if count < remaining {
+                remaining -= count;
+                date = end_of_year;
+                continue;
+            }
+
+            while remaining > 0 {
+                date = date.succ_opt()?;
+                if holidays.is_business_day(&date) {
+                    remaining -= 1;
+                }
+            }
+            return Some(date);
+        }
+    }
+
+    /// 指定した日付から指定した営業日数前の日付を取得します.
+    /// 指定した日付は数えません.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `days` - 営業日数
+    ///
+    /// # Returns
+    ///
+    /// 営業日数前の日付を返します. 日付が不正な場合や営業日が存在しない場合は `None` を返します.
+    pub fn sub_business_days<T: DateLike>(&self, date: T, days: u32) -> Option<NaiveDate> {
+        let mut date = date.date()?;
+        let mut remaining = days as i64;
+        // すべての曜日が定休日の場合は営業日が存在しない
+        if remaining > 0 && self.weekend().len() == 7 {
+            return None;
+        }
+
+        loop {
+            let holidays = YearHolidays::new(self, date.pred_opt()?.year());
+            let start_of_year = NaiveDate::from_ymd_opt(holidays.year, 1, 1)?;
+            let count = holidays.count(start_of_year, date.pred_opt()?);
+            if count < remaining {
+                remaining -= count;
+                date = start_of_year;
+                continue;
+            }
+
+            while remaining > 0 {
+                date = date.pred_opt()?;
+                if holidays.is_business_day(&date) {
+                    remaining -= 1;
+                }
+            }
+            return Some(date);
+        }
+    }
+
+    /// 指定した日付の翌営業日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 翌営業日を返します. 日付が不正な場合は `None` を返します.
+    pub fn next_business_day<T: DateLike>(&self, date: T) -> Option<NaiveDate> {
+        self.add_business_days(date, 1)
+    }
+
+    /// 指定した日付の前営業日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 前営業日を返します. 日付が不正な場合は `None` を返します.
+    pub fn previous_business_day<T: DateLike>(&self, date: T) -> Option<NaiveDate> {
+        self.sub_business_days(date, 1)
+    }
+
+    /// 指定した期間の営業日数を取得します.
+    /// 開始日は含まず, 終了日は含みます.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含まない)
+    /// * `end_date` - 終了日(含む)
+    ///
+    /// # Returns
+    ///
+    /// 営業日数を返します. 開始日が終了日より後の場合は負の値を返します.
+    /// 日付が不正な場合は `None` を返します.
+    pub fn business_days_between<T: DateLike>(&self, start_date: T, end_date: T) -> Option<i64> {
+        let start_date = start_date.date()?;
+        let end_date = end_date.date()?;
+        if start_date > end_date {
+            return self
+                .business_days_between(end_date, start_date)
+                .map(|days| -days);
+        }
+
+        let mut count = 0;
+        for year in start_date.year()..=end_date.year() {
+            let holidays = YearHolidays::new(self, year);
+            let start = NaiveDate::from_ymd_opt(year, 1, 1)?.max(start_date.succ_opt()?);
+            let end = NaiveDate::from_ymd_opt(year, 12, 31)?.min(end_date);
+            count += holidays.count(start, end);
+        }
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::Weekday;
+    use rstest::rstest;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y/%m/%d").unwrap()
+    }
+
+    #[rstest]
+    #[case("2024/09/13", 0, "2024/09/13")]
+    #[case("2024/09/13", 1, "2024/09/17")] // 土日と敬老の日を飛ばす
+    #[case("2024/09/14", 1, "2024/09/17")]
+    #[case("2024/12/27", 3, "2025/01/02")]
+    #[case("2019/04/26", 1, "2019/05/07")] // 2019年のゴールデンウィーク
+    fn test_add_business_days(#[case] start: &str, #[case] days: u32, #[case] expected: &str) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(
+            calendar.add_business_days(date(start), days),
+            Some(date(expected))
+        );
+    }
+
+    #[rstest]
+    #[case("2024/09/17", 1, "2024/09/13")]
+    #[case("2024/09/16", 1, "2024/09/13")]
+    #[case("2025/01/06", 3, "2024/12/31")]
+    #[case("2019/05/07", 1, "2019/04/26")]
+    fn test_sub_business_days(#[case] start: &str, #[case] days: u32, #[case] expected: &str) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(
+            calendar.sub_business_days(date(start), days),
+            Some(date(expected))
+        );
+    }
+
+    #[rstest]
+    #[case("2024/09/13", "2024/09/17", 1)]
+    #[case("2024/09/17", "2024/09/13", -1)]
+    #[case("2024/01/01", "2024/01/01", 0)]
+    #[case("2023/12/31", "2024/12/31", 248)]
+    fn test_business_days_between(#[case] start: &str, #[case] end: &str, #[case] expected: i64) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(
+            calendar.business_days_between(date(start), date(end)),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_matches_day_by_day() {
+        let calendar = HolidayCalendar::national();
+        let start = date("2023/12/20");
+        let mut expected = start;
+        for days in 1..=300 {
+            expected = expected.succ_opt().unwrap();
+            while calendar.is_no_workday(expected) {
+                expected = expected.succ_opt().unwrap();
+            }
+            if days % 23 != 0 {
+                continue;
+            }
+            assert_eq!(calendar.add_business_days(start, days), Some(expected));
+            assert_eq!(calendar.sub_business_days(expected, days), Some(start));
+            assert_eq!(
+                calendar.business_days_between(start, expected),
+                Some(days as i64)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(Weekend::SUNDAY, "2024/09/13", 1, "2024/09/14")]
+    #[case(Weekend::SUNDAY, "2024/09/14", 1, "2024/09/17")]
+    #[case(Weekend::new(&[Weekday::Wed]), "2024/09/17", 1, "2024/09/19")]
+    #[case(Weekend::new(&[Weekday::Wed]), "2024/09/13", 2, "2024/09/15")]
+    fn test_add_business_days_with_weekend(
+        #[case] weekend: Weekend,
+        #[case] start: &str,
+        #[case] days: u32,
+        #[case] expected: &str,
+    ) {
+        let calendar = HolidayCalendar::builder().weekend(weekend).build();
+        let end = calendar.add_business_days(date(start), days);
+        assert_eq!(end, Some(date(expected)));
+        assert_eq!(
+            calendar.business_days_between(date(start), date(expected)),
+            Some(days as i64)
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_without_business_days() {
+        let weekend = Weekend::new(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]);
+        let calendar = HolidayCalendar::builder().weekend(weekend).build();
+        assert_eq!(calendar.add_business_days(date("2024/09/13"), 1), None);
+        assert_eq!(
+            calendar.add_business_days(date("2024/09/13"), 0),
+            Some(date("2024/09/13"))
+        );
+    }
+
+    #[test]
+    fn test_add_many_business_days() {
+        let calendar = HolidayCalendar::national();
+        let start = date("2000/01/04");
+        let end = calendar.add_business_days(start, 10_000).unwrap();
+        assert_eq!(calendar.business_days_between(start, end), Some(10_000));
+        assert_eq!(calendar.sub_business_days(end, 10_000), Some(start));
+    }
+}
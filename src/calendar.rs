@@ -0,0 +1,1213 @@
+//! 祝日カレンダー
+//!
+//! 国民の祝日の規則に, 会社独自の休日などを追加したカレンダーを作成できます.
+//!
+//! ```
+//! use chrono::Weekday;
+//! use yasumi::{HolidayCalendar, HolidayId};
+//!
+//! let calendar = HolidayCalendar::builder()
+//!     .fixed("foundation_day", "創立記念日", 6, 1)
+//!     .fixed("obon", "お盆休み", 8, 14)
+//!     .nth_weekday("family_day", "ファミリーデー", 11, 3, Weekday::Fri)
+//!     .remove(HolidayId::MountainDay)
+//!     .build();
+//!
+//! assert_eq!(calendar.holiday_name("2024-06-01"), Some("創立記念日".to_string()));
+//! assert_eq!(calendar.holiday_name("2024-11-15"), Some("ファミリーデー".to_string()));
+//! assert_eq!(calendar.holiday_name("2024-08-11"), None);
+//! assert_eq!(calendar.holiday_name("2024-01-01"), Some("元日".to_string()));
+//! ```
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::{Arc, OnceLock};
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+use crate::datelike::DateLike;
+use crate::error::YasumiError;
+use crate::holiday::{Holiday, HolidayId, HolidayKind, HolidayStatus};
+use crate::law::{is_holiday_as_known_on, period_as_known_on, possible_dates_as_known_on, Period};
+use crate::locale::Locale;
+use crate::public_holiday::{
+    find_citizens_holiday, find_substitute_holiday, is_supported_year, supported_years, week_day,
+    CitizensHoliday, PublicHoliday, HOLIDAYS, SUBSTITUTE_HOLIDAY_PERIODS,
+};
+use crate::weekend::Weekend;
+use crate::year_table::YearTable;
+
+/// 祝日カレンダー
+///
+/// 祝日の規則の集合から, 振替休日と国民の休日を含めた祝日を判定します.
+/// `HolidayCalendar::national()` は国民の祝日のみを含むカレンダーです.
+///
+/// 対応範囲内の年の祝日は, 初めて参照したときに1年分をまとめて求めて保持します.
+/// 複製したカレンダーは保持した祝日を共有します.
+#[derive(Clone)]
+pub struct HolidayCalendar {
+    rules: Vec<Arc<dyn PublicHoliday>>,
+    weekend: Weekend,
+    known_on: Option<NaiveDate>,
+    /// 対応範囲の年ごとの祝日の表
+    years: Arc<[OnceLock<YearTable>]>,
+    /// ビルド時に生成した祝日の表を使うかどうか
+    #[cfg(feature = "static-table")]
+    static_table: bool,
+}
+
+impl Default for HolidayCalendar {
+    fn default() -> Self {
+        HolidayCalendar::national()
+    }
+}
+
+impl HolidayCalendar {
+    /// 国民の祝日のみを含むカレンダーを作成します.
+    ///
+    /// `static-table` フィーチャーが有効な場合, 対応範囲の年はビルド時に生成した祝日の表を使います.
+    pub fn national() -> HolidayCalendar {
+        HolidayCalendar {
+            #[cfg(feature = "static-table")]
+            static_table: true,
+            ..HolidayCalendar::builder().build()
+        }
+    }
+
+    /// 国民の祝日を初期値とするビルダーを作成します.
+    pub fn builder() -> HolidayCalendarBuilder {
+        HolidayCalendarBuilder {
+            rules: HOLIDAYS
+                .iter()
+                .map(|holiday| Arc::new(*holiday) as Arc<dyn PublicHoliday>)
+                .chain([Arc::new(CitizensHoliday) as Arc<dyn PublicHoliday>])
+                .collect(),
+            weekend: Weekend::default(),
+            known_on: None,
+        }
+    }
+
+    /// 規則を持たないビルダーを作成します.
+    ///
+    /// 国民の祝日を含まない, 独自の休日だけのカレンダーを作成するときに使います.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::empty_builder()
+    ///     .fixed("foundation_day", "創立記念日", 6, 1)
+    ///     .build();
+    /// assert!(calendar.is_holiday("2024-06-01"));
+    /// assert!(!calendar.is_holiday("2024-01-01"));
+    /// ```
+    pub fn empty_builder() -> HolidayCalendarBuilder {
+        HolidayCalendarBuilder {
+            rules: vec![],
+            weekend: Weekend::default(),
+            known_on: None,
+        }
+    }
+
+    /// このカレンダーを初期値とするビルダーを作成します.
+    pub fn to_builder(&self) -> HolidayCalendarBuilder {
+        HolidayCalendarBuilder {
+            rules: self.rules.clone(),
+            weekend: self.weekend,
+            known_on: self.known_on,
+        }
+    }
+
+    /// 指定した時点で公布されていた法令だけで祝日を判定するカレンダーを作成します.
+    ///
+    /// 後から公布された法令による祝日の新設・移動・廃止は反映されません.
+    /// 法令の期間を持たない独自の休日は, 時点に関係なく判定されます.
+    ///
+    /// # Arguments
+    ///
+    /// * `known_on` - 法令を参照する時点
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// // 2021年の海の日を7月22日に移す特例は2020年12月4日に公布された
+    /// let known_on = NaiveDate::from_ymd_opt(2020, 6, 1).unwrap();
+    /// let calendar = HolidayCalendar::national().as_known_on(known_on);
+    /// assert_eq!(calendar.holiday_name("2021-07-19"), Some("海の日".to_string()));
+    /// assert_eq!(calendar.holiday_name("2021-07-22"), None);
+    /// ```
+    pub fn as_known_on(&self, known_on: NaiveDate) -> HolidayCalendar {
+        self.to_builder().known_on(known_on).build()
+    }
+
+    /// 法令を参照する時点を取得します. 最新の法令で判定する場合は `None` を返します.
+    pub fn known_on(&self) -> Option<NaiveDate> {
+        self.known_on
+    }
+
+    /// 定休日とする曜日を取得します.
+    pub fn weekend(&self) -> Weekend {
+        self.weekend
+    }
+
+    /// 法令を参照する時点を取得します. 指定がない場合は最新の法令を参照します.
+    fn knowledge_date(&self) -> NaiveDate {
+        self.known_on.unwrap_or(NaiveDate::MAX)
+    }
+
+    /// 法令を参照する時点に従って, 指定した日付が規則の祝日かどうかを判定します.
+    fn matches(&self, rule: &dyn PublicHoliday, date: &NaiveDate) -> bool {
+        match self.known_on {
+            Some(known_on) if !rule.periods().is_empty() => {
+                is_holiday_as_known_on(rule.periods(), date, &known_on)
+            }
+            _ => rule.is_holiday(date),
+        }
+    }
+
+    /// 法令を参照する時点に従って, 規則の根拠となった期間を取得します.
+    fn legal_basis(&self, rule: &dyn PublicHoliday, date: &NaiveDate) -> Option<&'static Period> {
+        match self.known_on {
+            Some(known_on) => period_as_known_on(rule.periods(), date, &known_on),
+            None => rule.legal_basis(date),
+        }
+    }
+
+    /// 指定した日付に該当する規則を取得します.
+    /// 国民の休日は他の祝日から判定するため含めません.
+    fn rule_on(&self, date: &NaiveDate) -> Option<&dyn PublicHoliday> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.kind() != HolidayKind::CitizensHoliday)
+            .find(|rule| self.matches(rule.as_ref(), date))
+            .map(|rule| rule.as_ref())
+    }
+
+    /// 国民の休日の規則を取得します. 規則が削除されている場合は `None` を返します.
+    fn citizens_holiday_rule(&self) -> Option<&dyn PublicHoliday> {
+        self.rules
+            .iter()
+            .find(|rule| rule.kind() == HolidayKind::CitizensHoliday)
+            .map(|rule| rule.as_ref())
+    }
+
+    /// 祝日が存在しうる日付の範囲を取得します. 祝日が存在しえない場合は `None` を返します.
+    ///
+    /// 国民の休日は他の祝日の間にしかならないため, 範囲は国民の休日を除いた規則から求めます.
+    /// 振替休日は元の祝日より後になるため, 最後の日は規則の範囲より1週間後とします.
+    pub(crate) fn possible_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let known_on = self.knowledge_date();
+        self.rules
+            .iter()
+            .filter(|rule| rule.kind() != HolidayKind::CitizensHoliday)
+            .filter_map(|rule| match self.known_on {
+                Some(_) if !rule.periods().is_empty() => {
+                    possible_dates_as_known_on(rule.periods(), &known_on)
+                }
+                _ => rule.possible_dates(),
+            })
+            .filter(|dates| !dates.is_empty())
+            .map(|dates| (*dates.start(), *dates.end()))
+            .reduce(|(start, end), (other_start, other_end)| {
+                (start.min(other_start), end.max(other_end))
+            })
+            .map(|(start, end)| {
+                let end = end.checked_add_days(Days::new(7)).unwrap_or(NaiveDate::MAX);
+                (start, end)
+            })
+    }
+
+    /// 指定した年の祝日の表を取得します. 対応範囲外の年は `None` を返します.
+    fn year_table(&self, year: i32) -> Option<&YearTable> {
+        let index = usize::try_from(year.checked_sub(*supported_years().start())?).ok()?;
+        let table = self.years.get(index)?.get_or_init(|| {
+            #[cfg(feature = "static-table")]
+            if self.static_table {
+                if let Some(holidays) = crate::static_table::year_holidays(year) {
+                    return YearTable::new(holidays);
+                }
+            }
+            let start_date = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(NaiveDate::MIN);
+            let end_date = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(NaiveDate::MAX);
+            YearTable::new(self.holidays_between(start_date, end_date))
+        });
+        Some(table)
+    }
+
+    /// 祝日を取得します. 対応範囲内の年は祝日の表から取得します.
+    fn holiday_on(&self, date: NaiveDate) -> Option<Holiday> {
+        match self.year_table(date.year()) {
+            Some(table) => table.get(&date).cloned(),
+            None => self.calc_holiday(date),
+        }
+    }
+
+    /// 祝日かどうかを判定します. 対応範囲内の年は祝日の表から判定します.
+    fn is_holiday_on(&self, date: NaiveDate) -> bool {
+        match self.year_table(date.year()) {
+            Some(table) => table.contains(&date),
+            None => self.calc_holiday(date).is_some(),
+        }
+    }
+
+    /// 指定した期間(両端を含む)の祝日を取得します. 対応範囲内の年は祝日の表から取得します.
+    pub(crate) fn holidays_in(&self, start_date: NaiveDate, end_date: NaiveDate) -> Vec<Holiday> {
+        let mut holidays = vec![];
+        let mut start = start_date;
+        while start <= end_date {
+            let end = NaiveDate::from_ymd_opt(start.year(), 12, 31)
+                .unwrap_or(NaiveDate::MAX)
+                .min(end_date);
+            match self.year_table(start.year()) {
+                Some(table) => holidays.extend(
+                    table
+                        .holidays()
+                        .iter()
+                        .filter(|holiday| (start..=end).contains(&holiday.date))
+                        .cloned(),
+                ),
+                None => holidays.extend(self.holidays_between(start, end)),
+            }
+            match end.succ_opt() {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+        holidays
+    }
+
+    /// 祝日を判定します.
+    fn calc_holiday(&self, date: NaiveDate) -> Option<Holiday> {
+        self.calc_holiday_with(date, |date| self.rule_on(date))
+    }
+
+    /// 指定した規則の判定関数を使って祝日を判定します.
+    ///
+    /// 振替休日と国民の休日は, 独自の休日と1948年以前の祝祭日を除いた規則から判定します.
+    fn calc_holiday_with<'a, F>(&'a self, date: NaiveDate, rule_on: F) -> Option<Holiday>
+    where
+        F: Fn(&NaiveDate) -> Option<&'a dyn PublicHoliday>,
+    {
+        if let Some(holiday) = rule_on(&date) {
+            return Some(Holiday {
+                date,
+                id: holiday.id(),
+                name: holiday.name(),
+                kind: holiday.kind(),
+                substitute_for: None,
+                status: holiday.status_as_known_on(&date, &self.knowledge_date()),
+                legal_basis: self.legal_basis(holiday, &date),
+            });
+        }
+
+        let known_on = self.knowledge_date();
+        let national_rule_on = |date: &NaiveDate| {
+            rule_on(date).filter(|rule| {
+                !matches!(rule.kind(), HolidayKind::Custom | HolidayKind::FestivalDay)
+            })
+        };
+
+        // 振替休日
+        if let Some((substituted, status)) =
+            find_substitute_holiday(&date, &known_on, national_rule_on)
+        {
+            return Some(Holiday {
+                date,
+                id: HolidayId::SubstituteHoliday,
+                name: format!("{} 振替休日", substituted.name()),
+                kind: HolidayKind::Substitute,
+                substitute_for: Some(substituted.id()),
+                status,
+                legal_basis: period_as_known_on(SUBSTITUTE_HOLIDAY_PERIODS, &date, &known_on),
+            });
+        }
+
+        // 国民の休日
+        let rule = self.citizens_holiday_rule()?;
+        let status = find_citizens_holiday(&date, &known_on, national_rule_on)?;
+        Some(Holiday {
+            date,
+            id: rule.id(),
+            name: rule.name(),
+            kind: rule.kind(),
+            substitute_for: None,
+            status,
+            legal_basis: period_as_known_on(rule.periods(), &date, &known_on),
+        })
+    }
+
+    /// 指定した日付の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は祝日を返します. それ以外の場合は `None` を返します.
+    pub fn holiday_detail<T: DateLike>(&self, date: T) -> Option<Holiday> {
+        self.holiday_on(date.date()?)
+    }
+
+    /// 指定した日付の祝日名を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
+    pub fn holiday_name<T: DateLike>(&self, date: T) -> Option<String> {
+        self.holiday_detail(date).map(|holiday| holiday.name)
+    }
+
+    /// 指定した日付の祝日名を指定した言語で取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `locale` - 言語
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
+    pub fn holiday_name_in<T: DateLike>(&self, date: T, locale: Locale) -> Option<String> {
+        self.holiday_detail(date)
+            .map(|holiday| holiday.name_in(locale))
+    }
+
+    /// 指定した日付が祝日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は `true` を返します. それ以外の場合は `false` を返します.
+    pub fn is_holiday<T: DateLike>(&self, date: T) -> bool {
+        date.date().is_some_and(|date| self.is_holiday_on(date))
+    }
+
+    /// 指定した日付が定休日(既定では土日)または祝日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 定休日または祝日の場合は `true` を返します. それ以外の場合は `false` を返します.
+    /// 日付が不正な場合は `false` を返します.
+    pub fn is_no_workday<T: DateLike>(&self, date: T) -> bool {
+        let Some(date) = date.date() else {
+            return false;
+        };
+        if self.weekend.is_weekend(&date) {
+            return true;
+        }
+        self.is_holiday(date)
+    }
+
+    /// 指定した年月の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    /// * `month` - 月
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    pub fn month_holidays(&self, year: i32, month: u32) -> Vec<(NaiveDate, String)> {
+        into_pairs(self.month_holidays_detail(year, month))
+    }
+
+    /// 指定した年月の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    /// * `month` - 月
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    pub fn month_holidays_detail(&self, year: i32, month: u32) -> Vec<Holiday> {
+        let Some(start_date) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return vec![];
+        };
+        let end_date = start_date
+            .checked_add_months(Months::new(1))
+            .and_then(|date| date.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+        self.holidays_in(start_date, end_date)
+    }
+
+    /// 指定した年の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    pub fn year_holidays(&self, year: i32) -> Vec<(NaiveDate, String)> {
+        into_pairs(self.year_holidays_detail(year))
+    }
+
+    /// 指定した年の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    pub fn year_holidays_detail(&self, year: i32) -> Vec<Holiday> {
+        let (Some(start_date), Some(end_date)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) else {
+            return vec![];
+        };
+        if let Some(table) = self.year_table(year) {
+            return table.holidays().to_vec();
+        }
+        self.holidays_between(start_date, end_date)
+    }
+
+    /// 指定した期間の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含む)
+    /// * `end_date` - 終了日(含む)
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    pub fn between<T: DateLike>(&self, start_date: T, end_date: T) -> Vec<(NaiveDate, String)> {
+        into_pairs(self.between_detail(start_date, end_date))
+    }
+
+    /// 指定した期間の祝日を取得します.
+    /// 祝日が存在しない場合は空のリストを返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含む)
+    /// * `end_date` - 終了日(含む)
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します. 日付が不正な場合は空のリストを返します.
+    pub fn between_detail<T: DateLike>(&self, start_date: T, end_date: T) -> Vec<Holiday> {
+        let (Some(start_date), Some(end_date)) = (start_date.date(), end_date.date()) else {
+            return vec![];
+        };
+        self.holidays_in(start_date, end_date)
+    }
+
+    /// 指定した期間(両端を含む)の祝日を取得します.
+    ///
+    /// 規則は期間の前後の日も含めて日付ごとに一度だけ判定し,
+    /// 振替休日と国民の休日はその結果から求めます.
+    fn holidays_between(&self, start_date: NaiveDate, end_date: NaiveDate) -> Vec<Holiday> {
+        if start_date > end_date {
+            return vec![];
+        }
+
+        // 振替元の祝日を遡るため, 期間の1週間前から判定する
+        let from = start_date
+            .checked_sub_days(Days::new(7))
+            .unwrap_or(NaiveDate::MIN);
+        let to = end_date.succ_opt().unwrap_or(end_date);
+        let rules: HashMap<NaiveDate, &dyn PublicHoliday> = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .filter_map(|date| Some((date, self.rule_on(&date)?)))
+            .collect();
+        let rule_on = |date: &NaiveDate| {
+            if (from..=to).contains(date) {
+                rules.get(date).copied()
+            } else {
+                self.rule_on(date)
+            }
+        };
+
+        start_date
+            .iter_days()
+            .take_while(|date| *date <= end_date)
+            .filter_map(|date| self.calc_holiday_with(date, rule_on))
+            .collect()
+    }
+
+    /// 指定した日付の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は祝日を返します. それ以外の場合は `None` を返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_holiday_detail<T: DateLike>(&self, date: T) -> Result<Option<Holiday>, YasumiError> {
+        Ok(self.holiday_on(check_date(date)?))
+    }
+
+    /// 指定した日付の祝日名を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_holiday_name<T: DateLike>(&self, date: T) -> Result<Option<String>, YasumiError> {
+        Ok(self.try_holiday_detail(date)?.map(|holiday| holiday.name))
+    }
+
+    /// 指定した日付が祝日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 祝日の場合は `true` を返します. それ以外の場合は `false` を返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_is_holiday<T: DateLike>(&self, date: T) -> Result<bool, YasumiError> {
+        Ok(self.try_holiday_detail(date)?.is_some())
+    }
+
+    /// 指定した日付が定休日(既定では土日)または祝日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    ///
+    /// # Returns
+    ///
+    /// 定休日または祝日の場合は `true` を返します. それ以外の場合は `false` を返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_is_no_workday<T: DateLike>(&self, date: T) -> Result<bool, YasumiError> {
+        let date = check_date(date)?;
+        Ok(self.weekend.is_weekend(&date) || self.is_holiday_on(date))
+    }
+
+    /// 指定した年月の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    /// * `month` - 月
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+    /// 月が存在しない場合は `YasumiError::InvalidMonth` を返します.
+    pub fn try_month_holidays(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+        self.try_month_holidays_detail(year, month).map(into_pairs)
+    }
+
+    /// 指定した年月の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    /// * `month` - 月
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+    /// 月が存在しない場合は `YasumiError::InvalidMonth` を返します.
+    pub fn try_month_holidays_detail(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<Holiday>, YasumiError> {
+        check_year(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(YasumiError::InvalidMonth(month));
+        }
+        Ok(self.month_holidays_detail(year, month))
+    }
+
+    /// 指定した年の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_year_holidays(&self, year: i32) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+        self.try_year_holidays_detail(year).map(into_pairs)
+    }
+
+    /// 指定した年の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+    pub fn try_year_holidays_detail(&self, year: i32) -> Result<Vec<Holiday>, YasumiError> {
+        check_year(year)?;
+        Ok(self.year_holidays_detail(year))
+    }
+
+    /// 指定した期間の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含む)
+    /// * `end_date` - 終了日(含む)
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+    /// 開始日が終了日より後の場合は `YasumiError::InvalidRange` を返します.
+    pub fn try_between<T: DateLike>(
+        &self,
+        start_date: T,
+        end_date: T,
+    ) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+        self.try_between_detail(start_date, end_date)
+            .map(into_pairs)
+    }
+
+    /// 指定した期間の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含む)
+    /// * `end_date` - 終了日(含む)
+    ///
+    /// # Returns
+    ///
+    /// 祝日のリストを返します.
+    /// 日付が不正な場合は `YasumiError::InvalidDate` を,
+    /// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+    /// 開始日が終了日より後の場合は `YasumiError::InvalidRange` を返します.
+    pub fn try_between_detail<T: DateLike>(
+        &self,
+        start_date: T,
+        end_date: T,
+    ) -> Result<Vec<Holiday>, YasumiError> {
+        let start = check_date(start_date)?;
+        let end = check_date(end_date)?;
+        if start > end {
+            return Err(YasumiError::InvalidRange { start, end });
+        }
+        Ok(self.holidays_in(start, end))
+    }
+}
+
+/// 指定した年の祝日を判定できるかどうかを検証します.
+fn check_year(year: i32) -> Result<(), YasumiError> {
+    if !is_supported_year(year) {
+        return Err(YasumiError::UnsupportedYear(year));
+    }
+    Ok(())
+}
+
+/// 指定した日付を変換し, 祝日を判定できる年かどうかを検証します.
+fn check_date<T: DateLike>(date: T) -> Result<NaiveDate, YasumiError> {
+    let date = date.try_date()?;
+    check_year(date.year())?;
+    Ok(date)
+}
+
+/// 祝日カレンダーのビルダー
+///
+/// `HolidayCalendar::builder()` または `HolidayCalendar::empty_builder()` で作成します.
+#[derive(Clone)]
+pub struct HolidayCalendarBuilder {
+    rules: Vec<Arc<dyn PublicHoliday>>,
+    weekend: Weekend,
+    known_on: Option<NaiveDate>,
+}
+
+impl HolidayCalendarBuilder {
+    /// 祝日の規則を追加します.
+    ///
+    /// # Arguments
+    ///
+    /// * `holiday` - 祝日の規則
+    pub fn holiday<H: PublicHoliday + 'static>(mut self, holiday: H) -> Self {
+        self.rules.push(Arc::new(holiday));
+        self
+    }
+
+    /// 毎年同じ月日の休日を追加します.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 休日を識別するキー
+    /// * `name` - 休日名
+    /// * `month` - 月
+    /// * `day` - 日
+    pub fn fixed(self, key: &'static str, name: &str, month: u32, day: u32) -> Self {
+        self.holiday(FixedDateHoliday {
+            id: HolidayId::Custom(key),
+            name: name.to_string(),
+            month,
+            day,
+        })
+    }
+
+    /// 毎年同じ月日の休日を複数追加します. 追加した休日はすべて同じキーで識別されます.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 休日を識別するキー
+    /// * `name` - 休日名
+    /// * `dates` - 月日の一覧
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::builder()
+    ///     .fixed_dates("obon", "お盆休み", &[(8, 13), (8, 14), (8, 15), (8, 16)])
+    ///     .build();
+    /// assert!(calendar.is_holiday("2024-08-14"));
+    /// ```
+    pub fn fixed_dates(mut self, key: &'static str, name: &str, dates: &[(u32, u32)]) -> Self {
+        for (month, day) in dates {
+            self = self.fixed(key, name, *month, *day);
+        }
+        self
+    }
+
+    /// 指定した日付だけの休日を追加します.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 休日を識別するキー
+    /// * `name` - 休日名
+    /// * `date` - 日付
+    pub fn date(self, key: &'static str, name: &str, date: NaiveDate) -> Self {
+        self.holiday(SpecificDateHoliday {
+            id: HolidayId::Custom(key),
+            name: name.to_string(),
+            date,
+        })
+    }
+
+    /// 毎年指定した月の第n週の指定した曜日の休日を追加します.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 休日を識別するキー
+    /// * `name` - 休日名
+    /// * `month` - 月
+    /// * `week` - 週(1から5まで)
+    /// * `weekday` - 曜日
+    pub fn nth_weekday(
+        self,
+        key: &'static str,
+        name: &str,
+        month: u32,
+        week: u32,
+        weekday: Weekday,
+    ) -> Self {
+        self.holiday(NthWeekdayHoliday {
+            id: HolidayId::Custom(key),
+            name: name.to_string(),
+            month,
+            week,
+            weekday,
+        })
+    }
+
+    /// 指定した識別子の祝日の規則を削除します.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - 祝日の識別子
+    pub fn remove(mut self, id: HolidayId) -> Self {
+        self.rules.retain(|rule| rule.id() != id);
+        self
+    }
+
+    /// 指定した識別子の祝日の名前を変更します.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - 祝日の識別子
+    /// * `name` - 新しい祝日名
+    pub fn rename(mut self, id: HolidayId, name: &str) -> Self {
+        for rule in self.rules.iter_mut() {
+            if rule.id() == id {
+                *rule = Arc::new(RenamedHoliday {
+                    holiday: rule.clone(),
+                    name: name.to_string(),
+                });
+            }
+        }
+        self
+    }
+
+    /// 定休日とする曜日を設定します. 既定値は土曜日と日曜日です.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekend` - 定休日とする曜日
+    pub fn weekend(mut self, weekend: Weekend) -> Self {
+        self.weekend = weekend;
+        self
+    }
+
+    /// 指定した時点で公布されていた法令だけで祝日を判定します. 既定値は最新の法令です.
+    ///
+    /// # Arguments
+    ///
+    /// * `known_on` - 法令を参照する時点
+    pub fn known_on(mut self, known_on: NaiveDate) -> Self {
+        self.known_on = Some(known_on);
+        self
+    }
+
+    /// 祝日カレンダーを作成します.
+    pub fn build(self) -> HolidayCalendar {
+        HolidayCalendar {
+            rules: self.rules,
+            weekend: self.weekend,
+            known_on: self.known_on,
+            years: supported_years().map(|_| OnceLock::new()).collect(),
+            #[cfg(feature = "static-table")]
+            static_table: false,
+        }
+    }
+}
+
+/// 毎年同じ月日の休日
+pub struct FixedDateHoliday {
+    /// 休日の識別子
+    pub id: HolidayId,
+    /// 休日名
+    pub name: String,
+    /// 月
+    pub month: u32,
+    /// 日
+    pub day: u32,
+}
+
+impl PublicHoliday for FixedDateHoliday {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        date.month() == self.month && date.day() == self.day
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn id(&self) -> HolidayId {
+        self.id
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::Custom
+    }
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        // 存在しない月日は祝日になりえない
+        NaiveDate::from_ymd_opt(2000, self.month, self.day).map(|_| NaiveDate::MIN..=NaiveDate::MAX)
+    }
+}
+
+/// 毎年指定した月の第n週の指定した曜日の休日
+pub struct NthWeekdayHoliday {
+    /// 休日の識別子
+    pub id: HolidayId,
+    /// 休日名
+    pub name: String,
+    /// 月
+    pub month: u32,
+    /// 週(1から5まで)
+    pub week: u32,
+    /// 曜日
+    pub weekday: Weekday,
+}
+
+impl PublicHoliday for NthWeekdayHoliday {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        date.month() == self.month
+            && week_day(date, self.week, self.weekday.number_from_monday()) == Some(*date)
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn id(&self) -> HolidayId {
+        self.id
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::Custom
+    }
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        ((1..=12).contains(&self.month) && (1..=5).contains(&self.week))
+            .then_some(NaiveDate::MIN..=NaiveDate::MAX)
+    }
+}
+
+/// 指定した日付だけの休日
+pub struct SpecificDateHoliday {
+    /// 休日の識別子
+    pub id: HolidayId,
+    /// 休日名
+    pub name: String,
+    /// 日付
+    pub date: NaiveDate,
+}
+
+impl PublicHoliday for SpecificDateHoliday {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        *date == self.date
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn id(&self) -> HolidayId {
+        self.id
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::Custom
+    }
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        Some(self.date..=self.date)
+    }
+}
+
+/// 名前を変更した祝日
+struct RenamedHoliday {
+    holiday: Arc<dyn PublicHoliday>,
+    name: String,
+}
+
+impl PublicHoliday for RenamedHoliday {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.holiday.is_holiday(date)
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn id(&self) -> HolidayId {
+        self.holiday.id()
+    }
+    fn kind(&self) -> HolidayKind {
+        self.holiday.kind()
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        self.holiday.status(date)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        self.holiday.status_as_known_on(date, known_on)
+    }
+    fn periods(&self) -> &'static [Period] {
+        self.holiday.periods()
+    }
+    fn legal_basis(&self, date: &NaiveDate) -> Option<&'static Period> {
+        self.holiday.legal_basis(date)
+    }
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        self.holiday.possible_dates()
+    }
+}
+
+/// 祝日のリストを日付と祝日名の組のリストに変換します.
+pub(crate) fn into_pairs(holidays: Vec<Holiday>) -> Vec<(NaiveDate, String)> {
+    holidays
+        .into_iter()
+        .map(|holiday| (holiday.date, holiday.name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    fn company_calendar() -> HolidayCalendar {
+        HolidayCalendar::builder()
+            .fixed("foundation_day", "創立記念日", 6, 1)
+            .fixed("new_year_holiday", "年末年始休暇", 1, 2)
+            .nth_weekday("family_day", "ファミリーデー", 11, 3, Weekday::Fri)
+            .remove(HolidayId::MountainDay)
+            .rename(HolidayId::SportsDay, "体育の日")
+            .build()
+    }
+
+    #[rstest]
+    #[case("2024/06/01", Some("創立記念日".to_string()))]
+    #[case("2024/01/02", Some("年末年始休暇".to_string()))]
+    #[case("2024/11/15", Some("ファミリーデー".to_string()))]
+    #[case("2024/08/11", None)]
+    #[case("2024/08/12", None)]
+    #[case("2024/10/14", Some("体育の日".to_string()))]
+    #[case("2024/01/01", Some("元日".to_string()))]
+    #[case("2024/02/12", Some("建国記念の日 振替休日".to_string()))]
+    fn test_holiday_name(#[case] date: &str, #[case] expected: Option<String>) {
+        assert_eq!(company_calendar().holiday_name(date), expected);
+    }
+
+    #[test]
+    fn test_custom_holiday_is_not_substituted() {
+        // 2022年6月5日(日)の独自の休日は振り替えられない
+        let calendar = HolidayCalendar::builder()
+            .fixed("foundation_day", "創立記念日", 6, 5)
+            .build();
+        assert_eq!(
+            calendar.holiday_name("2022/06/05"),
+            Some("創立記念日".to_string())
+        );
+        assert_eq!(calendar.holiday_name("2022/06/06"), None);
+    }
+
+    #[test]
+    fn test_custom_holiday_does_not_create_citizens_holiday() {
+        // 11月2日は文化の日と創立記念日に挟まれるが国民の休日ではない
+        let calendar = HolidayCalendar::builder()
+            .fixed("foundation_day", "創立記念日", 11, 1)
+            .build();
+        assert_eq!(calendar.holiday_name("2023/11/02"), None);
+    }
+
+    #[test]
+    fn test_citizens_holiday_rule() {
+        // 2026年9月22日は敬老の日と秋分の日に挟まれる
+        assert_eq!(
+            HolidayCalendar::national().holiday_name("2026/09/22"),
+            Some("国民の休日".to_string())
+        );
+        let calendar = HolidayCalendar::builder()
+            .remove(HolidayId::CitizensHoliday)
+            .build();
+        assert_eq!(calendar.holiday_name("2026/09/22"), None);
+        let calendar = calendar
+            .to_builder()
+            .holiday(CitizensHoliday)
+            .rename(HolidayId::CitizensHoliday, "休日")
+            .build();
+        assert_eq!(
+            calendar.holiday_name("2026/09/22"),
+            Some("休日".to_string())
+        );
+        // 削除した祝日は国民の休日の判定にも使わない
+        let calendar = HolidayCalendar::builder()
+            .remove(HolidayId::RespectForTheAgedDay)
+            .build();
+        assert_eq!(calendar.holiday_name("2026/09/22"), None);
+    }
+
+    #[rstest]
+    #[case("2024/09/14", true)] // 土曜日
+    #[case("2024/09/15", true)] // 日曜日
+    #[case("2024/09/16", true)] // 祝日(敬老の日)
+    #[case("2024/09/18", true)] // 水曜日
+    #[case("2024/09/19", false)]
+    fn test_is_no_workday_with_weekend(#[case] date: &str, #[case] expected: bool) {
+        let calendar = HolidayCalendar::builder()
+            .weekend(Weekend::SATURDAY_AND_SUNDAY.with(Weekday::Wed))
+            .build();
+        assert_eq!(calendar.is_no_workday(date), expected);
+    }
+
+    #[rstest]
+    #[case("2020/06/01", "2021/07/19", Some("海の日"))]
+    #[case("2020/06/01", "2021/07/22", None)]
+    #[case("2020/06/01", "2021/08/11", Some("山の日"))]
+    #[case("2020/06/01", "2021/10/11", Some("スポーツの日"))]
+    #[case("2020/12/04", "2021/07/19", None)]
+    #[case("2020/12/04", "2021/07/22", Some("海の日"))]
+    #[case("2018/06/19", "2020/07/20", Some("海の日"))]
+    #[case("2018/06/19", "2020/10/12", Some("体育の日"))]
+    #[case("2017/06/15", "2020/02/23", None)]
+    #[case("2017/06/15", "2019/12/23", Some("天皇誕生日"))]
+    #[case("2018/12/13", "2019/05/01", None)]
+    #[case("2018/12/13", "2019/04/30", None)] // 天皇の即位の日がないため国民の休日にならない
+    #[case("2018/12/14", "2019/04/30", Some("国民の休日"))]
+    #[case("1973/04/11", "1973/04/30", None)] // 振替休日の導入前
+    #[case("1973/04/12", "1973/04/30", Some("天皇誕生日 振替休日"))]
+    fn test_as_known_on(
+        #[case] known_on: &str,
+        #[case] date: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let known_on = NaiveDate::parse_from_str(known_on, "%Y/%m/%d").unwrap();
+        let calendar = HolidayCalendar::national().as_known_on(known_on);
+        assert_eq!(
+            calendar.holiday_name(date),
+            expected.map(|name| name.to_string())
+        );
+    }
+
+    #[test]
+    fn test_as_known_on_legal_basis() {
+        let known_on = NaiveDate::from_ymd_opt(2020, 6, 1).unwrap();
+        let calendar = HolidayCalendar::national().as_known_on(known_on);
+        let holiday = calendar.holiday_detail("2021/07/19").unwrap();
+        assert_eq!(
+            holiday.legal_basis.unwrap().law.number,
+            "平成13年法律第59号"
+        );
+        // 独自の休日は時点に関係なく判定される
+        let calendar = company_calendar().as_known_on(known_on);
+        assert_eq!(calendar.known_on(), Some(known_on));
+        assert!(calendar.is_holiday("2030/06/01"));
+    }
+
+    #[rstest]
+    // 2027年の暦要項は2026年2月2日に公表された
+    #[case("2020/01/01", "2027/03/21", HolidayStatus::Projected)]
+    #[case("2026/02/01", "2027/09/23", HolidayStatus::Projected)]
+    #[case("2026/02/02", "2027/03/21", HolidayStatus::Announced)]
+    #[case("2020/01/01", "2020/03/20", HolidayStatus::Announced)]
+    #[case("2018/01/01", "2019/09/23", HolidayStatus::Projected)] // 2019年の暦要項の公表前
+    #[case("2018/01/01", "2024/01/01", HolidayStatus::Statutory)]
+    fn test_as_known_on_status(
+        #[case] known_on: &str,
+        #[case] date: &str,
+        #[case] expected: HolidayStatus,
+    ) {
+        let known_on = NaiveDate::parse_from_str(known_on, "%Y/%m/%d").unwrap();
+        let calendar = HolidayCalendar::national().as_known_on(known_on);
+        assert_eq!(calendar.holiday_detail(date).unwrap().status, expected);
+    }
+
+    #[test]
+    fn test_national_calendar_matches_free_functions() {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(calendar.year_holidays(2024), crate::year_holidays(2024));
+    }
+}
@@ -0,0 +1,235 @@
+//! 営業日の調整規則
+//!
+//! 休日に当たる日付を前後の営業日にずらす規則(`BusinessDayConvention`)を扱います.
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::calendar::HolidayCalendar;
+use crate::datelike::DateLike;
+
+/// 営業日調整の方法(ISDA の Business Day Convention)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// 調整しない
+    Unadjusted,
+    /// 翌営業日に調整する
+    Following,
+    /// 翌営業日に調整する. ただし月をまたぐ場合は前営業日に調整する
+    ModifiedFollowing,
+    /// `ModifiedFollowing` に月末ルールを加えたもの
+    ///
+    /// `add_months` で, 月の最終営業日を基準とした日付を移動先の月の最終営業日に調整します.
+    /// 1つの日付を調整する `adjust` では `ModifiedFollowing` と同じです.
+    ModifiedFollowingEndOfMonth,
+    /// 前営業日に調整する
+    Preceding,
+    /// 前営業日に調整する. ただし月をまたぐ場合は翌営業日に調整する
+    ModifiedPreceding,
+}
+
+impl HolidayCalendar {
+    /// 指定した日付が非営業日の場合に, 指定した方法で営業日に調整します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `convention` - 営業日調整の方法
+    ///
+    /// # Returns
+    ///
+    /// 調整後の日付を返します. 指定した日付が営業日の場合はそのまま返します.
+    /// 日付が不正な場合や営業日が存在しない場合は `None` を返します.
+    pub fn adjust<T: DateLike>(
+        &self,
+        date: T,
+        convention: BusinessDayConvention,
+    ) -> Option<NaiveDate> {
+        let date = date.date()?;
+        if convention == BusinessDayConvention::Unadjusted || self.is_business_day(date) {
+            return Some(date);
+        }
+
+        match convention {
+            BusinessDayConvention::Unadjusted => Some(date),
+            BusinessDayConvention::Following => self.next_business_day(date),
+            BusinessDayConvention::Preceding => self.previous_business_day(date),
+            BusinessDayConvention::ModifiedFollowing
+            | BusinessDayConvention::ModifiedFollowingEndOfMonth => {
+                let following = self.next_business_day(date)?;
+                if following.month() == date.month() {
+                    return Some(following);
+                }
+                self.previous_business_day(date)
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let preceding = self.previous_business_day(date)?;
+                if preceding.month() == date.month() {
+                    return Some(preceding);
+                }
+                self.next_business_day(date)
+            }
+        }
+    }
+
+    /// 指定した日付から指定した月数後の日付を, 指定した方法で営業日に調整します.
+    ///
+    /// 移動先の月に同じ日がない場合は月末日とします.
+    /// `ModifiedFollowingEndOfMonth` では, 指定した日付がその月の最終営業日の場合に,
+    /// 移動先の月の最終営業日を返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `months` - 月数. 負の値の場合は前の月に移動します
+    /// * `convention` - 営業日調整の方法
+    ///
+    /// # Returns
+    ///
+    /// 調整後の日付を返します. 日付が不正な場合や営業日が存在しない場合は `None` を返します.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use yasumi::{BusinessDayConvention, HolidayCalendar};
+    ///
+    /// let calendar = HolidayCalendar::national();
+    /// // 2024年6月28日(金)は6月の最終営業日
+    /// let date = calendar.add_months(
+    ///     "2024-06-28",
+    ///     1,
+    ///     BusinessDayConvention::ModifiedFollowingEndOfMonth,
+    /// );
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 7, 31));
+    /// let date = calendar.add_months("2024-06-28", 1, BusinessDayConvention::ModifiedFollowing);
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 7, 29));
+    /// ```
+    pub fn add_months<T: DateLike>(
+        &self,
+        date: T,
+        months: i32,
+        convention: BusinessDayConvention,
+    ) -> Option<NaiveDate> {
+        let date = date.date()?;
+        let shifted = match u32::try_from(months) {
+            Ok(months) => date.checked_add_months(Months::new(months))?,
+            Err(_) => date.checked_sub_months(Months::new(months.unsigned_abs()))?,
+        };
+        if convention == BusinessDayConvention::ModifiedFollowingEndOfMonth
+            && self.last_business_day_of_month(date) == Some(date)
+        {
+            return self.last_business_day_of_month(shifted);
+        }
+        self.adjust(shifted, convention)
+    }
+
+    /// 指定した日付の月の最終営業日を取得します. 月に営業日がない場合は `None` を返します.
+    fn last_business_day_of_month(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let first = date.with_day(1)?;
+        let last = first
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+        self.adjust(last, BusinessDayConvention::Preceding)
+            .filter(|business_day| *business_day >= first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2024/09/17", BusinessDayConvention::Following, "2024/09/17")] // 営業日
+    #[case("2024/09/14", BusinessDayConvention::Unadjusted, "2024/09/14")]
+    #[case("2024/09/14", BusinessDayConvention::Following, "2024/09/17")]
+    #[case("2024/09/14", BusinessDayConvention::ModifiedFollowing, "2024/09/17")]
+    #[case("2024/09/14", BusinessDayConvention::Preceding, "2024/09/13")]
+    #[case("2024/09/14", BusinessDayConvention::ModifiedPreceding, "2024/09/13")]
+    // 2024年8月31日(土): 翌営業日は9月2日
+    #[case("2024/08/31", BusinessDayConvention::Following, "2024/09/02")]
+    #[case("2024/08/31", BusinessDayConvention::ModifiedFollowing, "2024/08/30")]
+    // 2024年6月1日(土): 前営業日は5月31日
+    #[case("2024/06/01", BusinessDayConvention::Preceding, "2024/05/31")]
+    #[case("2024/06/01", BusinessDayConvention::ModifiedPreceding, "2024/06/03")]
+    // 2019年4月30日(国民の休日): 翌営業日は5月7日
+    #[case("2019/04/30", BusinessDayConvention::ModifiedFollowing, "2019/04/26")]
+    fn test_adjust(
+        #[case] date: &str,
+        #[case] convention: BusinessDayConvention,
+        #[case] expected: &str,
+    ) {
+        let expected = NaiveDate::parse_from_str(expected, "%Y/%m/%d").unwrap();
+        let calendar = HolidayCalendar::national();
+        assert_eq!(calendar.adjust(date, convention), Some(expected));
+    }
+
+    #[rstest]
+    // 2024年9月30日(月)は9月の最終営業日
+    #[case(
+        "2024/09/30",
+        1,
+        BusinessDayConvention::ModifiedFollowingEndOfMonth,
+        "2024/10/31"
+    )]
+    #[case(
+        "2024/09/30",
+        2,
+        BusinessDayConvention::ModifiedFollowingEndOfMonth,
+        "2024/11/29"
+    )]
+    #[case("2024/09/30", -1, BusinessDayConvention::ModifiedFollowingEndOfMonth, "2024/08/30")]
+    #[case(
+        "2024/09/30",
+        2,
+        BusinessDayConvention::ModifiedFollowing,
+        "2024/11/29"
+    )]
+    // 2024年6月28日(金)は6月の最終営業日. 7月28日は日曜日
+    #[case(
+        "2024/06/28",
+        1,
+        BusinessDayConvention::ModifiedFollowingEndOfMonth,
+        "2024/07/31"
+    )]
+    #[case(
+        "2024/06/28",
+        1,
+        BusinessDayConvention::ModifiedFollowing,
+        "2024/07/29"
+    )]
+    // 最終営業日でない日付は月末ルールの対象外
+    #[case(
+        "2024/06/27",
+        1,
+        BusinessDayConvention::ModifiedFollowingEndOfMonth,
+        "2024/07/29"
+    )]
+    // 2024年1月31日の1か月後は2月29日
+    #[case(
+        "2024/01/31",
+        1,
+        BusinessDayConvention::ModifiedFollowing,
+        "2024/02/29"
+    )]
+    #[case(
+        "2024/09/30",
+        0,
+        BusinessDayConvention::ModifiedFollowingEndOfMonth,
+        "2024/09/30"
+    )]
+    fn test_add_months(
+        #[case] date: &str,
+        #[case] months: i32,
+        #[case] convention: BusinessDayConvention,
+        #[case] expected: &str,
+    ) {
+        let expected = NaiveDate::parse_from_str(expected, "%Y/%m/%d").unwrap();
+        let calendar = HolidayCalendar::national();
+        assert_eq!(
+            calendar.add_months(date, months, convention),
+            Some(expected)
+        );
+    }
+}
@@ -1,26 +1,64 @@
+//! 日付として扱える型の定義
+//!
+//! `yasumi` の関数は [`DateLike`] を実装した型を受け取ります.
+//! 独自の日付型に [`DateLike`] を実装すると, その型を直接渡すことができます.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use yasumi::{is_holiday, DateLike};
+//!
+//! struct BusinessDate {
+//!     year: i32,
+//!     month: u32,
+//!     day: u32,
+//! }
+//!
+//! impl DateLike for BusinessDate {
+//!     fn date(&self) -> Option<NaiveDate> {
+//!         NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+//!     }
+//! }
+//!
+//! assert!(is_holiday(BusinessDate { year: 2024, month: 1, day: 1 }));
+//! ```
+
 use chrono::NaiveDate;
 
+use crate::error::YasumiError;
+
 /// `NaiveDate` に変換可能な型を表すトレイト
 pub trait DateLike {
+    /// `NaiveDate` に変換します. 変換できない場合は `None` を返します.
     fn date(&self) -> Option<NaiveDate>;
+
+    /// `NaiveDate` に変換します. 変換できない場合は `YasumiError::InvalidDate` を返します.
+    fn try_date(&self) -> Result<NaiveDate, YasumiError> {
+        self.date().ok_or(YasumiError::InvalidDate(None))
+    }
 }
 
 impl DateLike for String {
     fn date(&self) -> Option<NaiveDate> {
         match NaiveDate::parse_from_str(self, "%Y-%m-%d") {
             Ok(parsed_date) => Some(parsed_date),
-            Err(_) => match NaiveDate::parse_from_str(self, "%Y/%m/%d") {
-                Ok(parsed_date) => Some(parsed_date),
-                Err(_) => None,
-            },
+            Err(_) => NaiveDate::parse_from_str(self, "%Y/%m/%d").ok(),
         }
     }
+
+    fn try_date(&self) -> Result<NaiveDate, YasumiError> {
+        self.date()
+            .ok_or_else(|| YasumiError::InvalidDate(Some(self.clone())))
+    }
 }
 
 impl DateLike for &str {
     fn date(&self) -> Option<NaiveDate> {
         self.to_string().date()
     }
+
+    fn try_date(&self) -> Result<NaiveDate, YasumiError> {
+        self.to_string().try_date()
+    }
 }
 
 impl DateLike for NaiveDate {
@@ -0,0 +1,255 @@
+//! 連休の検出
+//!
+//! 定休日(既定では土日)と祝日が続く期間を連休として求めます.
+//! 期間の両端にかかる連休は期間の外まで延ばし, 連休全体を返します.
+
+use chrono::{Days, NaiveDate};
+
+use crate::calendar::HolidayCalendar;
+use crate::datelike::DateLike;
+use crate::holiday::Holiday;
+
+/// 連休を表す構造体
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DaysOff {
+    /// 初日
+    pub start: NaiveDate,
+    /// 最終日
+    pub end: NaiveDate,
+    /// 日数
+    pub days: u32,
+    /// 連休に含まれる祝日(日付順)
+    pub holidays: Vec<Holiday>,
+}
+
+impl HolidayCalendar {
+    /// 指定した期間にかかる連休を取得します.
+    ///
+    /// 休日(`is_no_workday` が `true` の日)が続く期間を1つの連休とします.
+    /// 期間の両端にかかる連休は期間の外まで延ばして返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - 開始日(含む)
+    /// * `end_date` - 終了日(含む)
+    /// * `min_days` - 最小の日数. これより短い連休は返しません
+    ///
+    /// # Returns
+    ///
+    /// 連休のリストを日付順に返します. 日付が不正な場合, 開始日が終了日より後の場合や
+    /// 両端にかかる連休の前後に営業日が存在しない場合は空のリストを返します.
+    pub fn consecutive_days_off<T: DateLike>(
+        &self,
+        start_date: T,
+        end_date: T,
+        min_days: u32,
+    ) -> Vec<DaysOff> {
+        let (Some(mut start), Some(mut end)) = (start_date.date(), end_date.date()) else {
+            return vec![];
+        };
+        if start > end {
+            return vec![];
+        }
+
+        // 両端にかかる連休は前後の営業日まで延ばす. 営業日が存在しない場合は連休が終わらない
+        if self.is_no_workday(start) {
+            let Some(previous) = self.previous_business_day(start) else {
+                return vec![];
+            };
+            start = previous + Days::new(1);
+        }
+        if self.is_no_workday(end) {
+            let Some(next) = self.next_business_day(end) else {
+                return vec![];
+            };
+            end = next - Days::new(1);
+        }
+
+        let mut holidays = self.holiday_iter(start..=end).peekable();
+        let mut runs = Vec::new();
+        let mut run: Option<DaysOff> = None;
+        for date in start.iter_days().take_while(|date| *date <= end) {
+            if !self.is_no_workday(date) {
+                runs.extend(run.take());
+                continue;
+            }
+            let run = run.get_or_insert_with(|| DaysOff {
+                start: date,
+                end: date,
+                days: 0,
+                holidays: vec![],
+            });
+            run.end = date;
+            run.days += 1;
+            while let Some(holiday) = holidays.next_if(|holiday| holiday.date == date) {
+                run.holidays.push(holiday);
+            }
+        }
+        runs.extend(run);
+        runs.retain(|run| run.days >= min_days);
+        runs
+    }
+
+    /// 指定した年に始まる3日以上の連休を取得します.
+    ///
+    /// `long_weekends_with(year, 3)` と同じです.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    ///
+    /// # Returns
+    ///
+    /// 連休のリストを日付順に返します.
+    pub fn long_weekends(&self, year: i32) -> Vec<DaysOff> {
+        self.long_weekends_with(year, 3)
+    }
+
+    /// 指定した年に始まる, 指定した日数以上の連休を取得します.
+    ///
+    /// 年末年始のように年をまたぐ連休は, 初日の年に含めます.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - 年
+    /// * `min_days` - 最小の日数. これより短い連休は返しません
+    ///
+    /// # Returns
+    ///
+    /// 連休のリストを日付順に返します.
+    pub fn long_weekends_with(&self, year: i32, min_days: u32) -> Vec<DaysOff> {
+        let (Some(start), Some(end)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) else {
+            return vec![];
+        };
+        let mut runs = self.consecutive_days_off(start, end, min_days);
+        runs.retain(|run| run.start >= start);
+        runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::weekend::Weekend;
+    use chrono::{Datelike, Weekday};
+    use rstest::rstest;
+
+    fn date(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap()
+    }
+
+    #[rstest]
+    // 2019年のゴールデンウィーク(10連休)
+    #[case("2019/05/01", "2019/05/01", 1, vec![("2019/04/27", "2019/05/06", 10, 8)])]
+    // 2015年のシルバーウィーク
+    #[case("2015/09/01", "2015/09/30", 3, vec![("2015/09/19", "2015/09/23", 5, 3)])]
+    #[case("2024/09/09", "2024/09/22", 1, vec![
+        ("2024/09/14", "2024/09/16", 3, 1),
+        ("2024/09/21", "2024/09/23", 3, 2),
+    ])]
+    #[case("2024/09/09", "2024/09/13", 1, vec![])] // 平日のみ
+    #[case("2024/09/22", "2024/09/09", 1, vec![])] // 開始日が終了日より後
+    fn test_consecutive_days_off(
+        #[case] start_date: &str,
+        #[case] end_date: &str,
+        #[case] min_days: u32,
+        #[case] expected: Vec<(&str, &str, u32, usize)>,
+    ) {
+        let calendar = HolidayCalendar::national();
+        let runs: Vec<(NaiveDate, NaiveDate, u32, usize)> = calendar
+            .consecutive_days_off(date(start_date), date(end_date), min_days)
+            .into_iter()
+            .map(|run| (run.start, run.end, run.days, run.holidays.len()))
+            .collect();
+        let expected: Vec<(NaiveDate, NaiveDate, u32, usize)> = expected
+            .into_iter()
+            .map(|(start, end, days, holidays)| (date(start), date(end), days, holidays))
+            .collect();
+        assert_eq!(runs, expected);
+    }
+
+    #[test]
+    fn test_extension_is_bounded() {
+        // 独自の休日で平日が埋まる期間は, 前後の営業日まで延ばす
+        let june = date("2024/06/03")
+            .iter_days()
+            .take_while(|date| *date <= self::date("2024/06/28"))
+            .fold(HolidayCalendar::empty_builder(), |builder, date| {
+                builder.date("closed", "休業日", date)
+            })
+            .build();
+        let runs = june.consecutive_days_off(date("2024/06/10"), date("2024/06/10"), 1);
+        assert_eq!(runs.len(), 1);
+        assert_eq!(
+            (runs[0].start, runs[0].end, runs[0].days),
+            (date("2024/06/01"), date("2024/06/30"), 30)
+        );
+        assert_eq!(runs[0].holidays.len(), 26);
+
+        // 前後に営業日が存在しない場合は空のリストを返す
+        let tuesdays_only = HolidayCalendar::empty_builder()
+            .weekend(Weekend::new(&[
+                Weekday::Mon,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ]))
+            .build();
+        assert_eq!(NaiveDate::MAX.weekday(), Weekday::Mon);
+        assert_eq!(
+            tuesdays_only.consecutive_days_off(NaiveDate::MAX, NaiveDate::MAX, 1),
+            vec![]
+        );
+        let seven_days = HolidayCalendar::empty_builder()
+            .weekend(Weekend::new(&[
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ]))
+            .build();
+        assert_eq!(
+            seven_days.consecutive_days_off(date("2024/06/01"), date("2024/06/30"), 1),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_long_weekends() {
+        let calendar = HolidayCalendar::national();
+        let runs = calendar.long_weekends(2024);
+        assert_eq!(runs.len(), 11);
+        assert!(runs.iter().all(|run| run.days >= 3));
+        assert_eq!(runs[0].start, date("2024/01/06"));
+
+        // 年をまたぐ連休は初日の年に含める
+        let bank = HolidayCalendar::bank();
+        let year_end = bank.long_weekends(2024).pop().unwrap();
+        assert_eq!(year_end.start, date("2024/12/31"));
+        assert_eq!(year_end.end, date("2025/01/05"));
+        assert_eq!(year_end.days, 6);
+        assert_ne!(bank.long_weekends(2025)[0].start, date("2024/12/31"));
+    }
+
+    #[rstest]
+    #[case(2024, 1, 53)] // 週末と3月20日(水)
+    #[case(2024, 3, 11)]
+    #[case(2024, 4, 1)] // 2024年5月3日から6日まで
+    #[case(2019, 10, 1)] // 2019年のゴールデンウィーク
+    #[case(2019, 11, 0)]
+    fn test_long_weekends_with(#[case] year: i32, #[case] min_days: u32, #[case] expected: usize) {
+        let calendar = HolidayCalendar::national();
+        let runs = calendar.long_weekends_with(year, min_days);
+        assert_eq!(runs.len(), expected);
+        assert!(runs.iter().all(|run| run.days >= min_days));
+    }
+}
@@ -0,0 +1,530 @@
+//! 春分・秋分の瞬間の計算
+//!
+//! Jean Meeus『Astronomical Algorithms』第27章の方法(VSOP87 に基づく平均分点と周期項の補正)で
+//! 太陽黄経が 0° または 180° になる力学時(TT)を求め, ΔT を差し引いて世界時に変換した後,
+//! 日本標準時(JST, UTC+9)で返します.
+//! 1951年から2050年までの誤差は1分程度です.
+//!
+//! 3001年から4000年までの春分日・秋分日は, グレゴリオ暦の400年周期で2601年から3000年までの
+//! 同じ位置の年の日付を用いる近似値です. 4001年以降は求めません.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
+
+use crate::holiday::HolidayStatus;
+
+/// 計算できる最初の年
+const FIRST_YEAR: i32 = 1000;
+
+/// 計算できる最後の年
+const LAST_YEAR: i32 = 3000;
+
+/// 3001年以降の近似に用いる400年周期の最初の年
+const FIRST_CYCLE_YEAR: i32 = LAST_YEAR - 399;
+
+/// 近似で求める最後の年
+const LAST_APPROXIMATED_YEAR: i32 = 4000;
+
+/// 暦要項で公表された春分日の最初の年
+///
+/// 国民の祝日に関する法律は1948年7月20日に施行されたため, 1948年3月は春季皇霊祭です.
+const FIRST_ANNOUNCED_VERNAL_YEAR: i32 = 1949;
+
+/// 暦要項で公表された秋分日の最初の年
+const FIRST_ANNOUNCED_AUTUMNAL_YEAR: i32 = 1948;
+
+/// 暦要項で公表された春分日(3月の日). 1949年から
+///
+/// 国立天文台(1988年以前は東京天文台)が毎年2月に翌年の暦要項を官報で公表します.
+/// 計算値ではなく公表値を記載し, 計算との差異はテストで確認します.
+const ANNOUNCED_VERNAL_EQUINOX_DAYS: [u8; 79] = [
+    21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, // 1949-1959
+    20, 21, 21, 21, 20, 21, 21, 21, 20, 21, // 1960-1969
+    21, 21, 20, 21, 21, 21, 20, 21, 21, 21, // 1970-1979
+    20, 21, 21, 21, 20, 21, 21, 21, 20, 21, // 1980-1989
+    21, 21, 20, 20, 21, 21, 20, 20, 21, 21, // 1990-1999
+    20, 20, 21, 21, 20, 20, 21, 21, 20, 20, // 2000-2009
+    21, 21, 20, 20, 21, 21, 20, 20, 21, 21, // 2010-2019
+    20, 20, 21, 21, 20, 20, 20, 21, // 2020-2027
+];
+
+/// 暦要項で公表された秋分日(9月の日). 1948年から
+const ANNOUNCED_AUTUMNAL_EQUINOX_DAYS: [u8; 80] = [
+    23, 23, // 1948-1949
+    23, 24, 23, 23, 23, 24, 23, 23, 23, 24, // 1950-1959
+    23, 23, 23, 24, 23, 23, 23, 24, 23, 23, // 1960-1969
+    23, 24, 23, 23, 23, 24, 23, 23, 23, 24, // 1970-1979
+    23, 23, 23, 23, 23, 23, 23, 23, 23, 23, // 1980-1989
+    23, 23, 23, 23, 23, 23, 23, 23, 23, 23, // 1990-1999
+    23, 23, 23, 23, 23, 23, 23, 23, 23, 23, // 2000-2009
+    23, 23, 22, 23, 23, 23, 22, 23, 23, 23, // 2010-2019
+    22, 23, 23, 23, 22, 23, 23, 23, // 2020-2027
+];
+
+/// 周期項の係数(A, B, C)
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// 春分・秋分の区別
+#[derive(Clone, Copy)]
+enum Equinox {
+    Vernal,
+    Autumnal,
+}
+
+/// 指定した年の春分の瞬間を日本標準時で取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年(1000年から3000年まで)
+///
+/// # Returns
+///
+/// 春分の瞬間を返します. 範囲外の年の場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::{Datelike, Timelike};
+/// use yasumi::vernal_equinox;
+///
+/// // 2024年3月20日 12:06 (JST)
+/// let equinox = vernal_equinox(2024).unwrap();
+/// assert_eq!((equinox.month(), equinox.day(), equinox.hour()), (3, 20, 12));
+/// ```
+pub fn vernal_equinox(year: i32) -> Option<DateTime<FixedOffset>> {
+    equinox(year, Equinox::Vernal)
+}
+
+/// 指定した年の秋分の瞬間を日本標準時で取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年(1000年から3000年まで)
+///
+/// # Returns
+///
+/// 秋分の瞬間を返します. 範囲外の年の場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::{Datelike, Timelike};
+/// use yasumi::autumnal_equinox;
+///
+/// // 2024年9月22日 21:44 (JST)
+/// let equinox = autumnal_equinox(2024).unwrap();
+/// assert_eq!((equinox.month(), equinox.day(), equinox.hour()), (9, 22, 21));
+/// ```
+pub fn autumnal_equinox(year: i32) -> Option<DateTime<FixedOffset>> {
+    equinox(year, Equinox::Autumnal)
+}
+
+/// 春分日(3月の日)を取得する
+///
+/// 暦要項で公表された日付があればそれを, なければ日本標準時で計算した日付を返す.
+/// 3001年から4000年までは近似値を返す.
+pub(crate) fn vernal_equinox_day(year: i32) -> Option<u32> {
+    announced_day(
+        &ANNOUNCED_VERNAL_EQUINOX_DAYS,
+        FIRST_ANNOUNCED_VERNAL_YEAR,
+        year,
+    )
+    .or_else(|| {
+        cycle_year(year)
+            .and_then(vernal_equinox)
+            .map(|instant| instant.day())
+    })
+}
+
+/// 秋分日(9月の日)を取得する
+///
+/// 暦要項で公表された日付があればそれを, なければ日本標準時で計算した日付を返す.
+/// 3001年から4000年までは近似値を返す.
+pub(crate) fn autumnal_equinox_day(year: i32) -> Option<u32> {
+    announced_day(
+        &ANNOUNCED_AUTUMNAL_EQUINOX_DAYS,
+        FIRST_ANNOUNCED_AUTUMNAL_YEAR,
+        year,
+    )
+    .or_else(|| {
+        cycle_year(year)
+            .and_then(autumnal_equinox)
+            .map(|instant| instant.day())
+    })
+}
+
+/// 3001年から4000年までの年を, グレゴリオ暦の400年周期で2601年から3000年までの同じ位置の年に対応させる
+///
+/// グレゴリオ暦の1年は太陽年より約0.0003日長いため, 分点の日付は約3300年で1日早まり, ずれは際限なく
+/// 大きくなる. 対応させる年との差は1400年以内のため, 4000年までのずれは半日程度にとどまる.
+/// 4001年以降は `None` を返す.
+fn cycle_year(year: i32) -> Option<i32> {
+    if year <= LAST_YEAR {
+        return Some(year);
+    }
+    (year <= LAST_APPROXIMATED_YEAR).then(|| FIRST_CYCLE_YEAR + (year - FIRST_CYCLE_YEAR) % 400)
+}
+
+/// 指定した時点で分かっていた, 指定した年の春分日の確からしさを取得する
+pub(crate) fn vernal_equinox_status(year: i32, known_on: &NaiveDate) -> HolidayStatus {
+    equinox_status(
+        &ANNOUNCED_VERNAL_EQUINOX_DAYS,
+        FIRST_ANNOUNCED_VERNAL_YEAR,
+        year,
+        known_on,
+    )
+}
+
+/// 指定した時点で分かっていた, 指定した年の秋分日の確からしさを取得する
+pub(crate) fn autumnal_equinox_status(year: i32, known_on: &NaiveDate) -> HolidayStatus {
+    equinox_status(
+        &ANNOUNCED_AUTUMNAL_EQUINOX_DAYS,
+        FIRST_ANNOUNCED_AUTUMNAL_YEAR,
+        year,
+        known_on,
+    )
+}
+
+/// 公表値の表にある年だけを `Announced` とし, 公表日より前の時点では `Projected` とする.
+/// 表より前の春季・秋季皇霊祭は当時の公表値を収録しておらず, 計算で求めるため `Projected` とする.
+fn equinox_status(days: &[u8], first_year: i32, year: i32, known_on: &NaiveDate) -> HolidayStatus {
+    let announced = announced_day(days, first_year, year).is_some();
+    if announced && announced_on(year).is_some_and(|date| date <= *known_on) {
+        return HolidayStatus::Announced;
+    }
+    HolidayStatus::Projected
+}
+
+/// 指定した年の春分日・秋分日が公表された日を取得する
+///
+/// 暦要項は前年2月の最初の平日に官報に掲載されるものとする.
+fn announced_on(year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year.checked_sub(1)?, 2, 1)?
+        .iter_days()
+        .find(|date| date.weekday().number_from_monday() <= 5)
+}
+
+fn announced_day(days: &[u8], first_year: i32, year: i32) -> Option<u32> {
+    let index = usize::try_from(year.checked_sub(first_year)?).ok()?;
+    days.get(index).map(|day| *day as u32)
+}
+
+fn equinox(year: i32, equinox: Equinox) -> Option<DateTime<FixedOffset>> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        return None;
+    }
+
+    // 世界時のユリウス日
+    let jd = julian_ephemeris_day(year, equinox) - delta_t(year as f64) / 86400.0;
+    let seconds = ((jd - 2440587.5) * 86400.0).round() as i64;
+    let jst = FixedOffset::east_opt(9 * 3600)?;
+    Some(DateTime::from_timestamp(seconds, 0)?.with_timezone(&jst))
+}
+
+/// 分点の力学時のユリウス日(JDE)を計算する
+fn julian_ephemeris_day(year: i32, equinox: Equinox) -> f64 {
+    let y = (year as f64 - 2000.0) / 1000.0;
+    let jde0 = match equinox {
+        Equinox::Vernal => {
+            2451623.80984 + 365242.37404 * y + 0.05169 * y.powi(2)
+                - 0.00411 * y.powi(3)
+                - 0.00057 * y.powi(4)
+        }
+        Equinox::Autumnal => {
+            2451810.21715 + 365242.01767 * y - 0.11575 * y.powi(2)
+                + 0.00337 * y.powi(3)
+                + 0.00078 * y.powi(4)
+        }
+    };
+
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+    jde0 + 0.00001 * s / delta_lambda
+}
+
+/// 地球時と世界時の差 ΔT(秒)を計算する
+///
+/// NOTE: https://eclipse.gsfc.nasa.gov/SEcat5/deltatpoly.html (Espenak & Meeus)
+fn delta_t(year: f64) -> f64 {
+    let long_term = |year: f64| {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    match year {
+        y if y < 1600.0 => long_term(y),
+        y if y < 1700.0 => {
+            let t = y - 1600.0;
+            120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+        }
+        y if y < 1800.0 => {
+            let t = y - 1700.0;
+            8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3)
+                - t.powi(4) / 1174000.0
+        }
+        y if y < 1860.0 => {
+            let t = y - 1800.0;
+            13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+                - 0.00037436 * t.powi(4)
+                + 0.0000121272 * t.powi(5)
+                - 0.0000001699 * t.powi(6)
+                + 0.000000000875 * t.powi(7)
+        }
+        y if y < 1900.0 => {
+            let t = y - 1860.0;
+            7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3)
+                - 0.0004473624 * t.powi(4)
+                + t.powi(5) / 233174.0
+        }
+        y if y < 1920.0 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        y if y < 1941.0 => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        y if y < 1961.0 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if y < 1986.0 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if y < 2005.0 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        y if y < 2050.0 => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        y if y < 2150.0 => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    /// 従来の近似式による春分日
+    ///
+    /// NOTE: http://mt-soft.sakura.ne.jp/kyozai/excel_high/200_jissen_kiso/60_syunbun.htm
+    fn approximate_vernal_equinox_day(year: i32) -> u32 {
+        let i = match year {
+            1900..=1979 => 20.8357,
+            1980..=2099 => 20.8431,
+            _ => 21.8510,
+        };
+        approximate_equinox_day(i, year)
+    }
+
+    /// 従来の近似式による秋分日
+    fn approximate_autumnal_equinox_day(year: i32) -> u32 {
+        let i = match year {
+            1900..=1979 => 23.2588,
+            1980..=2099 => 23.2488,
+            _ => 24.2488,
+        };
+        approximate_equinox_day(i, year)
+    }
+
+    fn approximate_equinox_day(i: f64, year: i32) -> u32 {
+        (i + 0.242194 * (year as f64 - 1980.0) - ((year as f64 - 1980.0) / 4.0).floor()).floor()
+            as u32
+    }
+
+    #[test]
+    fn test_equinox_instant() {
+        // 国立天文台 暦要項: 2024年 春分 3月20日 12:06, 秋分 9月22日 21:44
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 20)
+            .unwrap()
+            .and_hms_opt(12, 6, 0)
+            .unwrap();
+        let vernal = vernal_equinox(2024).unwrap();
+        assert!((vernal.naive_local() - expected).num_seconds().abs() <= 60);
+        assert_eq!(vernal.offset().local_minus_utc(), 9 * 3600);
+
+        let expected = NaiveDate::from_ymd_opt(2024, 9, 22)
+            .unwrap()
+            .and_hms_opt(21, 44, 0)
+            .unwrap();
+        let autumnal = autumnal_equinox(2024).unwrap();
+        assert!((autumnal.naive_local() - expected).num_seconds().abs() <= 60);
+
+        assert_eq!(vernal_equinox(999), None);
+        assert_eq!(autumnal_equinox(3001), None);
+        assert_eq!(vernal_equinox_day(2300), Some(21));
+    }
+
+    #[test]
+    fn test_approximation_after_last_year() {
+        assert_eq!(vernal_equinox_day(999), None);
+        assert_eq!(autumnal_equinox_day(999), None);
+        for year in [3001, 3400, 4000] {
+            let base = cycle_year(year).unwrap();
+            assert!((FIRST_CYCLE_YEAR..=LAST_YEAR).contains(&base));
+            assert_eq!((year - base) % 400, 0);
+            assert_eq!(vernal_equinox_day(year), vernal_equinox_day(base));
+            assert_eq!(autumnal_equinox_day(year), autumnal_equinox_day(base));
+        }
+        assert_eq!(cycle_year(3001), Some(2601));
+        assert_eq!(cycle_year(3000), Some(3000));
+
+        // 4001年以降はずれが大きくなるため求めない
+        assert_eq!(cycle_year(4001), None);
+        assert_eq!(vernal_equinox_day(4001), None);
+        assert_eq!(autumnal_equinox_day(262143), None);
+    }
+
+    #[rstest]
+    // 暦要項の公表値
+    #[case(1949, Some(21), Some(23))]
+    #[case(1951, Some(21), Some(24))]
+    #[case(1960, Some(20), Some(23))]
+    #[case(1979, Some(21), Some(24))]
+    #[case(2012, Some(20), Some(22))]
+    #[case(2027, Some(21), Some(23))]
+    fn test_announced_days(
+        #[case] year: i32,
+        #[case] vernal: Option<u32>,
+        #[case] autumnal: Option<u32>,
+    ) {
+        assert_eq!(vernal_equinox_day(year), vernal);
+        assert_eq!(autumnal_equinox_day(year), autumnal);
+    }
+
+    #[test]
+    fn test_computation_matches_announced_days() {
+        // 計算が公表値と一致しない年. 見つかった場合は公表値を変えずにここへ記録する
+        let known_disagreements: Vec<(&str, i32)> = vec![];
+
+        let mut disagreements = vec![];
+        for (index, day) in ANNOUNCED_VERNAL_EQUINOX_DAYS.iter().enumerate() {
+            let year = FIRST_ANNOUNCED_VERNAL_YEAR + index as i32;
+            if vernal_equinox(year).unwrap().day() != *day as u32 {
+                disagreements.push(("春分", year));
+            }
+        }
+        for (index, day) in ANNOUNCED_AUTUMNAL_EQUINOX_DAYS.iter().enumerate() {
+            let year = FIRST_ANNOUNCED_AUTUMNAL_YEAR + index as i32;
+            if autumnal_equinox(year).unwrap().day() != *day as u32 {
+                disagreements.push(("秋分", year));
+            }
+        }
+        assert_eq!(disagreements, known_disagreements);
+    }
+
+    #[test]
+    fn test_equinox_status() {
+        let latest = NaiveDate::MAX;
+        assert_eq!(
+            vernal_equinox_status(2027, &latest),
+            HolidayStatus::Announced
+        );
+        assert_eq!(
+            autumnal_equinox_status(2027, &latest),
+            HolidayStatus::Announced
+        );
+        assert_eq!(
+            vernal_equinox_status(2028, &latest),
+            HolidayStatus::Projected
+        );
+        assert_eq!(
+            autumnal_equinox_status(2028, &latest),
+            HolidayStatus::Projected
+        );
+        assert_eq!(
+            autumnal_equinox_status(1948, &latest),
+            HolidayStatus::Announced
+        );
+        // 公表値を収録していない春季・秋季皇霊祭は計算値
+        assert_eq!(
+            vernal_equinox_status(1948, &latest),
+            HolidayStatus::Projected
+        );
+        assert_eq!(
+            vernal_equinox_status(1878, &latest),
+            HolidayStatus::Projected
+        );
+        assert_eq!(
+            autumnal_equinox_status(1930, &latest),
+            HolidayStatus::Projected
+        );
+
+        // 2027年の暦要項は2026年2月2日(月)に公表された
+        assert_eq!(announced_on(2027), NaiveDate::from_ymd_opt(2026, 2, 2));
+        let before = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let after = NaiveDate::from_ymd_opt(2026, 2, 2).unwrap();
+        assert_eq!(
+            vernal_equinox_status(2027, &before),
+            HolidayStatus::Projected
+        );
+        assert_eq!(
+            vernal_equinox_status(2027, &after),
+            HolidayStatus::Announced
+        );
+        assert_eq!(
+            autumnal_equinox_status(2026, &before),
+            HolidayStatus::Announced
+        );
+    }
+
+    #[test]
+    fn test_matches_approximation() {
+        for year in 1900..=2150 {
+            assert_eq!(
+                vernal_equinox_day(year),
+                Some(approximate_vernal_equinox_day(year)),
+                "{year}"
+            );
+            // 2107年の秋分は9月23日 23時36分頃であり, 近似式は1日ずれる
+            if year == 2107 {
+                assert_eq!(approximate_autumnal_equinox_day(year), 24);
+                assert_eq!(autumnal_equinox_day(year), Some(23));
+                continue;
+            }
+            assert_eq!(
+                autumnal_equinox_day(year),
+                Some(approximate_autumnal_equinox_day(year)),
+                "{year}"
+            );
+        }
+    }
+}
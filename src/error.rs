@@ -0,0 +1,65 @@
+//! エラーの型
+//!
+//! `try_` で始まる関数などが返す `YasumiError` を定義します.
+
+use std::fmt;
+
+use chrono::NaiveDate;
+
+/// `yasumi` のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YasumiError {
+    /// 日付として解釈できない入力
+    ///
+    /// 入力が文字列の場合は, その文字列を保持します.
+    InvalidDate(Option<String>),
+    /// 対応していない年
+    UnsupportedYear(i32),
+    /// 存在しない月
+    InvalidMonth(u32),
+    /// 開始日が終了日より後の期間
+    InvalidRange {
+        /// 開始日
+        start: NaiveDate,
+        /// 終了日
+        end: NaiveDate,
+    },
+}
+
+impl fmt::Display for YasumiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YasumiError::InvalidDate(Some(input)) => write!(f, "invalid date: {:?}", input),
+            YasumiError::InvalidDate(None) => write!(f, "invalid date"),
+            YasumiError::UnsupportedYear(year) => write!(f, "unsupported year: {}", year),
+            YasumiError::InvalidMonth(month) => write!(f, "invalid month: {}", month),
+            YasumiError::InvalidRange { start, end } => {
+                write!(f, "invalid range: {} is after {}", start, end)
+            }
+        }
+    }
+}
+
+impl std::error::Error for YasumiError {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            YasumiError::InvalidDate(Some("2024-13-01".to_string())).to_string(),
+            "invalid date: \"2024-13-01\""
+        );
+        assert_eq!(
+            YasumiError::InvalidRange {
+                start: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            }
+            .to_string(),
+            "invalid range: 2024-12-31 is after 2024-01-01"
+        );
+    }
+}
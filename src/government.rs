@@ -0,0 +1,115 @@
+//! 行政機関の休日
+//!
+//! 行政機関の休日に関する法律(昭和63年法律第91号)により, 行政機関は土日祝と年末年始(12月29日から1月3日まで)に閉庁します.
+//! また, 同法第2条により, 期限が行政機関の休日に当たる場合は休日の翌日が期限となります.
+
+use std::sync::OnceLock;
+
+use chrono::NaiveDate;
+
+use crate::calendar::HolidayCalendar;
+use crate::convention::BusinessDayConvention;
+use crate::datelike::DateLike;
+
+impl HolidayCalendar {
+    /// 行政機関の休日のカレンダーを作成します.
+    ///
+    /// 国民の祝日に加えて, 12月29日から1月3日までを含みます.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let government = HolidayCalendar::government();
+    /// assert_eq!(government.holiday_name("2025-12-29"), Some("年末年始の休日".to_string()));
+    /// ```
+    pub fn government() -> HolidayCalendar {
+        HolidayCalendar::builder()
+            .fixed_dates(
+                "government_year_end_holiday",
+                "年末年始の休日",
+                &[(12, 29), (12, 30), (12, 31), (1, 2), (1, 3)],
+            )
+            .build()
+    }
+}
+
+/// 行政機関の休日のカレンダーを取得します.
+fn government_calendar() -> &'static HolidayCalendar {
+    static GOVERNMENT_CALENDAR: OnceLock<HolidayCalendar> = OnceLock::new();
+    GOVERNMENT_CALENDAR.get_or_init(HolidayCalendar::government)
+}
+
+/// 指定した日付が行政機関の休日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 行政機関の休日の場合は `true` を返します. それ以外の場合は `false` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::is_government_closed;
+///
+/// assert_eq!(is_government_closed("2025-12-29"), true);
+/// assert_eq!(is_government_closed("2025-12-26"), false);
+/// ```
+pub fn is_government_closed<T: DateLike>(date: T) -> bool {
+    government_calendar().is_no_workday(date)
+}
+
+/// 期限の特例を適用した期限日を取得します.
+/// 期限が行政機関の休日に当たる場合は, 休日の翌日(次の開庁日)を期限とします.
+///
+/// # Arguments
+///
+/// * `date` - 本来の期限日
+///
+/// # Returns
+///
+/// 期限日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::statutory_deadline;
+///
+/// // 2025年12月31日が期限の場合は2026年1月5日が期限となる
+/// let date = statutory_deadline("2025-12-31");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2026, 1, 5));
+/// ```
+pub fn statutory_deadline<T: DateLike>(date: T) -> Option<NaiveDate> {
+    government_calendar().adjust(date, BusinessDayConvention::Following)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2024/12/27", false)]
+    #[case("2024/12/28", true)] // 土曜日
+    #[case("2024/12/30", true)]
+    #[case("2025/01/01", true)] // 元日
+    #[case("2025/01/03", true)]
+    #[case("2025/01/06", false)]
+    #[case("2025/11/24", true)] // 勤労感謝の日 振替休日
+    fn test_is_government_closed(#[case] date: &str, #[case] expected: bool) {
+        assert_eq!(is_government_closed(date), expected);
+    }
+
+    #[rstest]
+    #[case("2025/03/14", "2025/03/14")]
+    #[case("2025/03/15", "2025/03/17")] // 土曜日
+    #[case("2025/03/20", "2025/03/21")] // 春分の日
+    #[case("2024/12/29", "2025/01/06")]
+    fn test_statutory_deadline(#[case] date: &str, #[case] expected: &str) {
+        let expected = NaiveDate::parse_from_str(expected, "%Y/%m/%d").unwrap();
+        assert_eq!(statutory_deadline(date), Some(expected));
+    }
+}
@@ -0,0 +1,183 @@
+//! 祝日の値と種類
+//!
+//! 祝日を表す `Holiday` と, 祝日の識別子や種類, 状態を表す列挙型を定義します.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::law::Period;
+use crate::public_holiday::is_supported_year;
+
+/// 祝日を識別するための列挙型
+///
+/// 祝日名の文字列に依存せずに祝日を判別するために使用します.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayId {
+    /// 元日
+    NewYearsDay,
+    /// 成人の日
+    ComingOfAgeDay,
+    /// 建国記念の日
+    NationalFoundationDay,
+    /// 天皇誕生日
+    EmperorsBirthday,
+    /// 春分の日
+    VernalEquinoxDay,
+    /// みどりの日
+    GreeneryDay,
+    /// 昭和の日
+    ShowaDay,
+    /// 憲法記念日
+    ConstitutionMemorialDay,
+    /// こどもの日
+    ChildrensDay,
+    /// 海の日
+    MarineDay,
+    /// 山の日
+    MountainDay,
+    /// 敬老の日
+    RespectForTheAgedDay,
+    /// 秋分の日
+    AutumnalEquinoxDay,
+    /// 体育の日
+    HealthAndSportsDay,
+    /// スポーツの日
+    SportsDay,
+    /// 文化の日
+    CultureDay,
+    /// 勤労感謝の日
+    LaborThanksgivingDay,
+    /// 皇太子・明仁親王の結婚の儀
+    ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito,
+    /// 昭和天皇の大喪の礼
+    ImperialEventsTheFuneralOfEmperorShowa,
+    /// 即位の礼正殿の儀
+    ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor,
+    /// 皇太子・皇太子徳仁親王の結婚の儀
+    ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito,
+    /// 天皇の即位の日
+    ImperialEventsTheDayOfTheEmperorsEnthronement,
+    /// 即位礼正殿の儀
+    ImperialEventsTheEnthronementCeremony,
+    /// 四方拝
+    Shihohai,
+    /// 元始祭
+    GenshiSai,
+    /// 新年宴会
+    ShinnenEnkai,
+    /// 孝明天皇祭
+    KomeiTennoSai,
+    /// 紀元節
+    KigenSetsu,
+    /// 春季皇霊祭
+    ShunkiKoreiSai,
+    /// 神武天皇祭
+    JimmuTennoSai,
+    /// 明治天皇祭
+    MeijiTennoSai,
+    /// 天長節
+    TenchoSetsu,
+    /// 天長節祝日
+    TenchoSetsuShukujitsu,
+    /// 秋季皇霊祭
+    ShukiKoreiSai,
+    /// 神嘗祭
+    KannameSai,
+    /// 明治節
+    MeijiSetsu,
+    /// 新嘗祭
+    NiinameSai,
+    /// 大正天皇祭
+    TaishoTennoSai,
+    /// 振替休日
+    SubstituteHoliday,
+    /// 国民の休日
+    CitizensHoliday,
+    /// 独自に定義した祝日
+    ///
+    /// 値は祝日を識別するためのキーです.
+    Custom(&'static str),
+}
+
+/// 祝日の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// 国民の祝日に関する法律で定められた祝日
+    Statutory,
+    /// 振替休日
+    Substitute,
+    /// 国民の休日
+    CitizensHoliday,
+    /// 皇室の行事に伴う一度限りの休日
+    ImperialEvent,
+    /// 1948年以前に太政官布告および勅令で定められた祝祭日
+    FestivalDay,
+    /// 独自に定義した祝日
+    Custom,
+}
+
+/// 祝日の日付の確からしさ
+///
+/// 確からしい順に並んでおり, 比較すると不確かなほど大きくなります.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HolidayStatus {
+    /// 法律で日付が確定している祝日
+    Statutory,
+    /// 国立天文台の暦要項で日付が公表された祝日
+    Announced,
+    /// 計算で求めた日付で, 公表値を収録していない祝日
+    Projected,
+}
+
+/// 祝日を表す構造体
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    /// 日付
+    pub date: NaiveDate,
+    /// 祝日の識別子
+    pub id: HolidayId,
+    /// 祝日名
+    pub name: String,
+    /// 祝日の種類
+    pub kind: HolidayKind,
+    /// 振替休日の場合は振替元の祝日の識別子
+    pub substitute_for: Option<HolidayId>,
+    /// 日付の確からしさ
+    pub status: HolidayStatus,
+    /// 祝日の根拠となった法令と規則の期間. 独自に定義した祝日は `None`
+    pub legal_basis: Option<&'static Period>,
+}
+
+impl Holiday {
+    /// 振替休日かどうかを判定します.
+    pub fn is_substitute(&self) -> bool {
+        self.kind == HolidayKind::Substitute
+    }
+
+    /// 日付が公表前の計算値かどうかを判定します.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::holiday_detail;
+    ///
+    /// assert!(!holiday_detail("2025-03-20").unwrap().is_projected());
+    /// assert!(holiday_detail("2090-03-20").unwrap().is_projected());
+    /// ```
+    pub fn is_projected(&self) -> bool {
+        self.status == HolidayStatus::Projected
+    }
+
+    /// 対応範囲外の年の祝日で, 現行の規則を延長した推定値かどうかを判定します.
+    ///
+    /// 独自に定義した祝日は推定値として扱いません.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::holiday_detail;
+    ///
+    /// assert!(!holiday_detail("2024-01-01").unwrap().is_extrapolated());
+    /// assert!(holiday_detail("2200-01-01").unwrap().is_extrapolated());
+    /// ```
+    pub fn is_extrapolated(&self) -> bool {
+        self.kind != HolidayKind::Custom && !is_supported_year(self.date.year())
+    }
+}
@@ -0,0 +1,343 @@
+//! 祝日のイテレータ
+//!
+//! 期間の祝日を日付順に1件ずつ返します. 祝日は年単位でまとめて求めるため,
+//! 祝日でない日を1日ずつ判定することはありません.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use yasumi::HolidayCalendar;
+//!
+//! let calendar = HolidayCalendar::national();
+//! let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+//! let names: Vec<String> = calendar
+//!     .holiday_iter(today..)
+//!     .take(3)
+//!     .map(|holiday| holiday.name)
+//!     .collect();
+//! assert_eq!(names, vec!["元日", "成人の日", "建国記念の日"]);
+//! ```
+
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::HolidayCalendar;
+use crate::holiday::Holiday;
+
+/// 期間の祝日を日付順に返すイテレータ
+///
+/// `HolidayCalendar::holiday_iter` または `yasumi::holiday_iter` で作成します.
+/// 終わりのない期間も指定でき, 逆順にも辿れます.
+///
+/// 規則ごとの `PublicHoliday::possible_dates` の範囲の外は読み込まないため, 祝日が存在しない方向に
+/// 辿ってもすぐに終わります. 毎年同じ月日の休日のように期限のない規則は, その範囲を制限しません.
+/// 振替休日と国民の休日は他の規則の範囲の中にしかならないため, 範囲を広げません.
+pub struct HolidayIter<'a> {
+    calendar: &'a HolidayCalendar,
+    /// まだ読み込んでいない期間(両端を含む)
+    remaining: Option<(NaiveDate, NaiveDate)>,
+    /// 先頭側で読み込んだ祝日
+    front: VecDeque<Holiday>,
+    /// 末尾側で読み込んだ祝日(末尾から順に並ぶ)
+    back: VecDeque<Holiday>,
+}
+
+impl<'a> HolidayIter<'a> {
+    fn new<R: RangeBounds<NaiveDate>>(calendar: &'a HolidayCalendar, range: R) -> HolidayIter<'a> {
+        let start = match range.start_bound() {
+            Bound::Included(date) => Some(*date),
+            Bound::Excluded(date) => date.succ_opt(),
+            Bound::Unbounded => Some(NaiveDate::MIN),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(date) => Some(*date),
+            Bound::Excluded(date) => date.pred_opt(),
+            Bound::Unbounded => Some(NaiveDate::MAX),
+        };
+        // 祝日が存在しうる範囲の外は読み込まない
+        let remaining = start
+            .zip(end)
+            .zip(calendar.possible_dates())
+            .map(|((start, end), (first, last))| (start.max(first), end.min(last)))
+            .filter(|(start, end)| start <= end);
+        HolidayIter {
+            calendar,
+            remaining,
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+        }
+    }
+
+    /// 残りの期間の最初の年の祝日を先頭側に読み込みます.
+    fn load_front(&mut self) -> bool {
+        let Some((start, end)) = self.remaining else {
+            return false;
+        };
+        let year_end = NaiveDate::from_ymd_opt(start.year(), 12, 31)
+            .unwrap_or(NaiveDate::MAX)
+            .min(end);
+        self.front
+            .extend(self.calendar.holidays_in(start, year_end));
+        self.remaining = year_end
+            .succ_opt()
+            .filter(|next| *next <= end)
+            .map(|next| (next, end));
+        true
+    }
+
+    /// 残りの期間の最後の年の祝日を末尾側に読み込みます.
+    fn load_back(&mut self) -> bool {
+        let Some((start, end)) = self.remaining else {
+            return false;
+        };
+        let year_start = NaiveDate::from_ymd_opt(end.year(), 1, 1)
+            .unwrap_or(NaiveDate::MIN)
+            .max(start);
+        self.back
+            .extend(self.calendar.holidays_in(year_start, end).into_iter().rev());
+        self.remaining = year_start
+            .pred_opt()
+            .filter(|prev| start <= *prev)
+            .map(|prev| (start, prev));
+        true
+    }
+}
+
+impl Iterator for HolidayIter<'_> {
+    type Item = Holiday;
+
+    fn next(&mut self) -> Option<Holiday> {
+        loop {
+            if let Some(holiday) = self.front.pop_front() {
+                return Some(holiday);
+            }
+            if !self.load_front() {
+                return self.back.pop_back();
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for HolidayIter<'_> {
+    fn next_back(&mut self) -> Option<Holiday> {
+        loop {
+            if let Some(holiday) = self.back.pop_front() {
+                return Some(holiday);
+            }
+            if !self.load_back() {
+                return self.front.pop_back();
+            }
+        }
+    }
+}
+
+impl FusedIterator for HolidayIter<'_> {}
+
+impl HolidayCalendar {
+    /// 指定した期間の祝日を日付順に返すイテレータを作成します.
+    ///
+    /// 祝日は年単位で必要になったときに求めます. 終わりのない期間も指定できます.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - 期間. `start..`, `..=end`, `start..end` などの形で指定します
+    ///
+    /// # Returns
+    ///
+    /// 祝日のイテレータを返します.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let calendar = HolidayCalendar::national();
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// // 2024年1月1日より前の最後の祝日
+    /// let holiday = calendar.holiday_iter(..date).next_back().unwrap();
+    /// assert_eq!(holiday.date, NaiveDate::from_ymd_opt(2023, 11, 23).unwrap());
+    /// ```
+    pub fn holiday_iter<R: RangeBounds<NaiveDate>>(&self, range: R) -> HolidayIter<'_> {
+        HolidayIter::new(self, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::holiday::{HolidayId, HolidayKind};
+    use crate::public_holiday::{CitizensHoliday, PublicHoliday};
+    use crate::search::Inclusion;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_matches_between() {
+        let calendar = HolidayCalendar::national();
+        let expected = calendar.between_detail(date(2019, 3, 1), date(2026, 2, 11));
+        let holidays: Vec<Holiday> = calendar
+            .holiday_iter(date(2019, 3, 1)..=date(2026, 2, 11))
+            .collect();
+        assert_eq!(holidays, expected);
+
+        let mut reversed: Vec<Holiday> = calendar
+            .holiday_iter(date(2019, 3, 1)..=date(2026, 2, 11))
+            .rev()
+            .collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn test_both_ends() {
+        let calendar = HolidayCalendar::national();
+        let mut iter = calendar.holiday_iter(date(2023, 12, 1)..date(2025, 1, 1));
+        assert_eq!(iter.next().unwrap().date, date(2024, 1, 1));
+        assert_eq!(iter.next_back().unwrap().date, date(2024, 11, 23));
+        let rest: Vec<Holiday> = iter.by_ref().collect();
+        assert_eq!(rest.len(), 19);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let calendar = HolidayCalendar::national();
+        let first = calendar.holiday_iter(..).next().unwrap();
+        assert_eq!(first.date, date(1873, 11, 3));
+        let last = calendar
+            .holiday_iter(..date(1873, 11, 4))
+            .next_back()
+            .unwrap();
+        assert_eq!(last.date, date(1873, 11, 3));
+        assert_eq!(calendar.holiday_iter(..date(1873, 11, 3)).next_back(), None);
+        assert_eq!(
+            calendar.holiday_iter(date(3000, 1, 1)..).take(10).count(),
+            10
+        );
+
+        // 規則がないカレンダーは祝日を返さない
+        let empty = HolidayCalendar::empty_builder().build();
+        assert_eq!(empty.holiday_iter(..).next(), None);
+        assert_eq!(empty.holiday_iter(..).next_back(), None);
+    }
+
+    #[test]
+    fn test_custom_rules_are_bounded() {
+        // 規則の範囲の外は読み込まないため, 祝日のない方向に辿ってもすぐに終わる
+        let calendar = HolidayCalendar::builder()
+            .date("x", "X", date(2030, 1, 1))
+            .build();
+        assert_eq!(calendar.holiday_iter(..date(1800, 1, 1)).next_back(), None);
+
+        let custom = HolidayCalendar::empty_builder()
+            .date("x", "X", date(2030, 1, 1))
+            .build();
+        assert_eq!(custom.holiday_iter(..date(2030, 1, 1)).next_back(), None);
+        assert_eq!(custom.holiday_iter(date(2030, 1, 2)..).next(), None);
+        let holidays: Vec<Holiday> = custom.holiday_iter(..).collect();
+        assert_eq!(holidays.len(), 1);
+        assert_eq!(holidays[0].date, date(2030, 1, 1));
+
+        // 存在しない月日の休日は祝日になりえない
+        let never = HolidayCalendar::empty_builder()
+            .fixed("x", "X", 2, 30)
+            .build();
+        assert_eq!(never.holiday_iter(..).next(), None);
+
+        // 毎年の休日は期限なく辿れる
+        let yearly = HolidayCalendar::empty_builder()
+            .fixed("x", "X", 6, 1)
+            .build();
+        assert_eq!(
+            yearly
+                .holiday_iter(..date(1000, 1, 1))
+                .next_back()
+                .unwrap()
+                .date,
+            date(999, 6, 1)
+        );
+    }
+
+    #[test]
+    fn test_derived_rules_are_bounded() {
+        // 国民の休日の規則だけでは祝日が存在しえないため, 年を辿らずにすぐに終わる
+        let citizens_only = HolidayCalendar::empty_builder()
+            .holiday(CitizensHoliday)
+            .build();
+        assert_eq!(citizens_only.possible_dates(), None);
+        assert_eq!(citizens_only.holiday_iter(date(2024, 1, 1)..).next(), None);
+        assert_eq!(
+            citizens_only.next_holiday(date(2024, 1, 1), Inclusion::Exclusive),
+            None
+        );
+        assert_eq!(
+            citizens_only.previous_holiday(date(2024, 1, 1), Inclusion::Exclusive),
+            None
+        );
+
+        // 独自の休日の範囲に, 振替休日のための1週間を加えた範囲になる
+        let custom = HolidayCalendar::empty_builder()
+            .holiday(CitizensHoliday)
+            .date("x", "X", date(2030, 1, 1))
+            .build();
+        assert_eq!(
+            custom.possible_dates(),
+            Some((date(2030, 1, 1), date(2030, 1, 8)))
+        );
+    }
+
+    #[test]
+    fn test_rules_without_periods_are_unbounded() {
+        /// 法令の期間を持たない独自の規則
+        struct FoundationDay;
+        impl PublicHoliday for FoundationDay {
+            fn is_holiday(&self, date: &NaiveDate) -> bool {
+                date.month() == 6 && date.day() == 1
+            }
+            fn name(&self) -> String {
+                "創立記念日".to_string()
+            }
+            fn id(&self) -> HolidayId {
+                HolidayId::Custom("foundation_day")
+            }
+            fn kind(&self) -> HolidayKind {
+                HolidayKind::Custom
+            }
+        }
+
+        let calendar = HolidayCalendar::empty_builder()
+            .holiday(FoundationDay)
+            .build();
+        assert!(calendar.is_holiday(date(2200, 6, 1)));
+        assert_eq!(
+            calendar
+                .holiday_iter(date(2200, 1, 1)..)
+                .next()
+                .unwrap()
+                .date,
+            date(2200, 6, 1)
+        );
+        assert_eq!(
+            calendar.next_holiday(date(2150, 7, 1), Inclusion::Exclusive),
+            Some((date(2151, 6, 1), "創立記念日".to_string()))
+        );
+        assert_eq!(
+            calendar.previous_holiday(date(1800, 1, 1), Inclusion::Exclusive),
+            Some((date(1799, 6, 1), "創立記念日".to_string()))
+        );
+        assert_eq!(
+            calendar
+                .holiday_iter(..date(1873, 1, 1))
+                .next_back()
+                .unwrap()
+                .date,
+            date(1872, 6, 1)
+        );
+    }
+}
@@ -0,0 +1,672 @@
+//! 祝日を定めた法令と, 法令ごとの祝日の規則
+//!
+//! 祝日の規則は, 法令で定められた施行日から始まる期間([`Period`])の集合として表します.
+//! ある日付に複数の期間が該当する場合は, 最も新しく公布された法令の期間を適用します.
+//!
+//! 各祝日の期間は [`PublicHoliday::periods`](crate::PublicHoliday::periods) で,
+//! 祝日の根拠となった期間は [`Holiday::legal_basis`](crate::Holiday::legal_basis) で取得できます.
+//!
+//! ```
+//! use yasumi::holiday_detail;
+//!
+//! let holiday = holiday_detail("2021-07-22").unwrap();
+//! let period = holiday.legal_basis.unwrap();
+//! assert_eq!(period.law.number, "令和2年法律第68号");
+//! assert_eq!(period.to_string(), "2021年7月22日 (令和2年法律第68号, 2021年1月1日から2021年12月31日まで)");
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::equinox::{autumnal_equinox_day, vernal_equinox_day};
+use crate::public_holiday::week_day;
+
+/// 定数として日付を作成する
+pub(crate) const fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => date,
+        None => panic!("invalid date"),
+    }
+}
+
+/// 祝日を定めた法令
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Law {
+    /// 法令番号
+    pub number: &'static str,
+    /// 題名(題名のない法令は件名)
+    pub title: &'static str,
+    /// 祝日に関する内容の要約
+    pub description: &'static str,
+    /// 公布日
+    pub promulgated: NaiveDate,
+}
+
+impl fmt::Display for Law {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.title, self.number)
+    }
+}
+
+/// 年中祭日祝日ノ休暇日ヲ定ム(明治6年太政官布告第344号)
+pub const MEIJI_6_DECREE_344: Law = Law {
+    number: "明治6年太政官布告第344号",
+    title: "年中祭日祝日ノ休暇日ヲ定ム",
+    description: "祝祭日を休暇日と定める布告",
+    promulgated: ymd(1873, 10, 14),
+};
+
+/// 春季皇霊祭・秋季皇霊祭を休暇日に加える太政官布告(明治11年太政官布告第23号)
+pub const MEIJI_11_DECREE_23: Law = Law {
+    number: "明治11年太政官布告第23号",
+    title: "春季皇霊祭及び秋季皇霊祭を祭日に加える件",
+    description: "春季皇霊祭・秋季皇霊祭を休暇日に加える",
+    promulgated: ymd(1878, 6, 5),
+};
+
+/// 神嘗祭を10月17日に改める太政官布告(明治12年太政官布告第27号)
+pub const MEIJI_12_DECREE_27: Law = Law {
+    number: "明治12年太政官布告第27号",
+    title: "神嘗祭を十月十七日に改める件",
+    description: "神嘗祭を10月17日に改める",
+    promulgated: ymd(1879, 7, 5),
+};
+
+/// 休日ニ関スル件(大正元年勅令第19号)
+pub const TAISHO_1_ORDINANCE_19: Law = Law {
+    number: "大正元年勅令第19号",
+    title: "休日ニ関スル件",
+    description: "大正の祝祭日を定める勅令",
+    promulgated: ymd(1912, 9, 3),
+};
+
+/// 天長節祝日を休日に加える勅令(大正2年勅令第259号)
+pub const TAISHO_2_ORDINANCE_259: Law = Law {
+    number: "大正2年勅令第259号",
+    title: "休日ニ関スル件中改正ノ件",
+    description: "天長節祝日を休日に加える",
+    promulgated: ymd(1913, 7, 16),
+};
+
+/// 休日ニ関スル件(昭和2年勅令第25号)
+pub const SHOWA_2_ORDINANCE_25: Law = Law {
+    number: "昭和2年勅令第25号",
+    title: "休日ニ関スル件",
+    description: "昭和の祝祭日を定める勅令",
+    promulgated: ymd(1927, 3, 3),
+};
+
+/// 国民の祝日に関する法律(昭和23年法律第178号)
+pub const SHOWA_23_LAW_178: Law = Law {
+    number: "昭和23年法律第178号",
+    title: "国民の祝日に関する法律",
+    description: "国民の祝日を定める法律",
+    promulgated: ymd(1948, 7, 20),
+};
+
+/// 皇太子明仁親王の結婚の儀の行われる日を休日とする法律(昭和34年法律第16号)
+pub const SHOWA_34_LAW_16: Law = Law {
+    number: "昭和34年法律第16号",
+    title: "皇太子明仁親王の結婚の儀の行われる日を休日とする法律",
+    description: "皇太子明仁親王の結婚の儀の日を休日とする",
+    promulgated: ymd(1959, 3, 17),
+};
+
+/// 敬老の日・体育の日・建国記念の日を加える改正(昭和41年法律第86号)
+pub const SHOWA_41_LAW_86: Law = Law {
+    number: "昭和41年法律第86号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "敬老の日・体育の日・建国記念の日を加える改正",
+    promulgated: ymd(1966, 6, 25),
+};
+
+/// 建国記念の日となる日を定める政令(昭和41年政令第376号)
+pub const SHOWA_41_CABINET_ORDER_376: Law = Law {
+    number: "昭和41年政令第376号",
+    title: "建国記念の日となる日を定める政令",
+    description: "建国記念の日となる日を定める政令",
+    promulgated: ymd(1966, 12, 9),
+};
+
+/// 振替休日を設ける改正(昭和48年法律第10号)
+pub const SHOWA_48_LAW_10: Law = Law {
+    number: "昭和48年法律第10号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "振替休日を設ける改正",
+    promulgated: ymd(1973, 4, 12),
+};
+
+/// 国民の休日を設ける改正(昭和60年法律第103号)
+pub const SHOWA_60_LAW_103: Law = Law {
+    number: "昭和60年法律第103号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "国民の休日を設ける改正",
+    promulgated: ymd(1985, 12, 27),
+};
+
+/// 昭和天皇の大喪の礼の行われる日を休日とする法律(平成元年法律第4号)
+pub const HEISEI_1_LAW_4: Law = Law {
+    number: "平成元年法律第4号",
+    title: "昭和天皇の大喪の礼の行われる日を休日とする法律",
+    description: "昭和天皇の大喪の礼の日を休日とする",
+    promulgated: ymd(1989, 2, 17),
+};
+
+/// 天皇誕生日を12月23日とし, みどりの日を設ける改正(平成元年法律第5号)
+pub const HEISEI_1_LAW_5: Law = Law {
+    number: "平成元年法律第5号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "天皇誕生日を12月23日とし, みどりの日を設ける改正",
+    promulgated: ymd(1989, 2, 17),
+};
+
+/// 即位礼正殿の儀の行われる日を休日とする法律(平成2年法律第24号)
+pub const HEISEI_2_LAW_24: Law = Law {
+    number: "平成2年法律第24号",
+    title: "即位礼正殿の儀の行われる日を休日とする法律",
+    description: "即位礼正殿の儀の日を休日とする",
+    promulgated: ymd(1990, 6, 1),
+};
+
+/// 皇太子徳仁親王の結婚の儀の行われる日を休日とする法律(平成5年法律第32号)
+pub const HEISEI_5_LAW_32: Law = Law {
+    number: "平成5年法律第32号",
+    title: "皇太子徳仁親王の結婚の儀の行われる日を休日とする法律",
+    description: "皇太子徳仁親王の結婚の儀の日を休日とする",
+    promulgated: ymd(1993, 4, 30),
+};
+
+/// 海の日を設ける改正(平成7年法律第22号)
+pub const HEISEI_7_LAW_22: Law = Law {
+    number: "平成7年法律第22号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "海の日を設ける改正",
+    promulgated: ymd(1995, 3, 8),
+};
+
+/// 成人の日・体育の日を月曜日とする改正(平成10年法律第141号)
+pub const HEISEI_10_LAW_141: Law = Law {
+    number: "平成10年法律第141号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "成人の日・体育の日を月曜日とする改正",
+    promulgated: ymd(1998, 10, 21),
+};
+
+/// 海の日・敬老の日を月曜日とする改正(平成13年法律第59号)
+pub const HEISEI_13_LAW_59: Law = Law {
+    number: "平成13年法律第59号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "海の日・敬老の日を月曜日とする改正",
+    promulgated: ymd(2001, 6, 22),
+};
+
+/// 昭和の日を設け, 振替休日を改める改正(平成17年法律第43号)
+pub const HEISEI_17_LAW_43: Law = Law {
+    number: "平成17年法律第43号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "昭和の日を設け, 振替休日を改める改正",
+    promulgated: ymd(2005, 5, 20),
+};
+
+/// 山の日を設ける改正(平成26年法律第43号)
+pub const HEISEI_26_LAW_43: Law = Law {
+    number: "平成26年法律第43号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "山の日を設ける改正",
+    promulgated: ymd(2014, 5, 30),
+};
+
+/// 天皇の退位等に関する皇室典範特例法(平成29年法律第63号)
+pub const HEISEI_29_LAW_63: Law = Law {
+    number: "平成29年法律第63号",
+    title: "天皇の退位等に関する皇室典範特例法",
+    description: "天皇誕生日を2月23日に改める",
+    promulgated: ymd(2017, 6, 16),
+};
+
+/// 2020年の海の日・スポーツの日・山の日を移す特例(平成30年法律第55号)
+pub const HEISEI_30_LAW_55: Law = Law {
+    number: "平成30年法律第55号",
+    title: "平成三十二年東京オリンピック競技大会・東京パラリンピック競技大会特別措置法及び平成三十一年ラグビーワールドカップ大会特別措置法の一部を改正する法律",
+    description: "2020年の海の日・スポーツの日・山の日を移す特例",
+    promulgated: ymd(2018, 6, 20),
+};
+
+/// 体育の日をスポーツの日とする改正(平成30年法律第57号)
+pub const HEISEI_30_LAW_57: Law = Law {
+    number: "平成30年法律第57号",
+    title: "国民の祝日に関する法律の一部を改正する法律",
+    description: "体育の日をスポーツの日とする改正",
+    promulgated: ymd(2018, 6, 20),
+};
+
+/// 天皇の即位の日及び即位礼正殿の儀の行われる日を休日とする法律(平成30年法律第99号)
+pub const HEISEI_30_LAW_99: Law = Law {
+    number: "平成30年法律第99号",
+    title: "天皇の即位の日及び即位礼正殿の儀の行われる日を休日とする法律",
+    description: "天皇の即位の日及び即位礼正殿の儀の日を休日とする",
+    promulgated: ymd(2018, 12, 14),
+};
+
+/// 2021年の海の日・スポーツの日・山の日を移す特例(令和2年法律第68号)
+pub const REIWA_2_LAW_68: Law = Law {
+    number: "令和2年法律第68号",
+    title: "平成三十二年東京オリンピック競技大会・東京パラリンピック競技大会特別措置法等の一部を改正する法律",
+    description: "2021年の海の日・スポーツの日・山の日を移す特例",
+    promulgated: ymd(2020, 12, 4),
+};
+
+/// 祝日の日付の決め方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateRule {
+    /// 毎年の決まった月日
+    Fixed { month: u32, day: u32 },
+    /// 指定した月の第n週の曜日
+    NthWeekday {
+        month: u32,
+        week: u32,
+        weekday: Weekday,
+    },
+    /// 春分日
+    VernalEquinox,
+    /// 秋分日
+    AutumnalEquinox,
+    /// 特定の日付
+    Date(NaiveDate),
+    /// 日曜日に当たる祝日の翌日(振替休日)
+    ///
+    /// 翌日が祝日の場合は振り替えません.
+    /// 他の祝日との関係で決まるため, この規則だけでは判定しません.
+    SubstituteNextDay,
+    /// 日曜日に当たる祝日の後の, 最も近い祝日でない日(振替休日)
+    ///
+    /// 他の祝日との関係で決まるため, この規則だけでは判定しません.
+    SubstituteNextNonHoliday,
+    /// 前日と翌日が祝日である日(国民の休日). 日曜日と振替休日を除く
+    ///
+    /// 他の祝日との関係で決まるため, この規則だけでは判定しません.
+    BetweenHolidaysExceptSunday,
+    /// 前日と翌日が祝日である日(国民の休日)
+    ///
+    /// 他の祝日との関係で決まるため, この規則だけでは判定しません.
+    BetweenHolidays,
+}
+
+impl DateRule {
+    /// 指定した日付がこの規則の日付かどうかを判定する
+    fn matches(&self, date: &NaiveDate) -> bool {
+        match *self {
+            DateRule::Fixed { month, day } => date.month() == month && date.day() == day,
+            DateRule::NthWeekday {
+                month,
+                week,
+                weekday,
+            } => {
+                date.month() == month
+                    && week_day(date, week, weekday.number_from_monday()) == Some(*date)
+            }
+            DateRule::VernalEquinox => {
+                date.month() == 3 && vernal_equinox_day(date.year()) == Some(date.day())
+            }
+            DateRule::AutumnalEquinox => {
+                date.month() == 9 && autumnal_equinox_day(date.year()) == Some(date.day())
+            }
+            DateRule::Date(target) => *date == target,
+            DateRule::SubstituteNextDay
+            | DateRule::SubstituteNextNonHoliday
+            | DateRule::BetweenHolidaysExceptSunday
+            | DateRule::BetweenHolidays => false,
+        }
+    }
+}
+
+impl fmt::Display for DateRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateRule::Fixed { month, day } => write!(f, "{month}月{day}日"),
+            DateRule::NthWeekday {
+                month,
+                week,
+                weekday,
+            } => write!(f, "{month}月の第{week}{}", weekday_name(*weekday)),
+            DateRule::VernalEquinox => write!(f, "春分日"),
+            DateRule::AutumnalEquinox => write!(f, "秋分日"),
+            DateRule::Date(date) => write!(f, "{}", date.format("%Y年%-m月%-d日")),
+            DateRule::SubstituteNextDay => write!(f, "日曜日に当たる祝日の翌日"),
+            DateRule::SubstituteNextNonHoliday => {
+                write!(f, "日曜日に当たる祝日の後の最も近い祝日でない日")
+            }
+            DateRule::BetweenHolidaysExceptSunday => {
+                write!(f, "前日と翌日が祝日である日(日曜日と振替休日を除く)")
+            }
+            DateRule::BetweenHolidays => write!(f, "前日と翌日が祝日である日"),
+        }
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "月曜日",
+        Weekday::Tue => "火曜日",
+        Weekday::Wed => "水曜日",
+        Weekday::Thu => "木曜日",
+        Weekday::Fri => "金曜日",
+        Weekday::Sat => "土曜日",
+        Weekday::Sun => "日曜日",
+    }
+}
+
+/// 法令で定められた祝日の規則が適用される期間
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    /// 根拠となる法令
+    pub law: &'static Law,
+    /// 適用を開始する日(施行日)
+    pub from: NaiveDate,
+    /// 適用を終了する日. 特例のように期間が限られている場合のみ指定します.
+    pub until: Option<NaiveDate>,
+    /// 日付の決め方. 祝日が廃止された場合は `None`
+    pub rule: Option<DateRule>,
+}
+
+impl Period {
+    /// 指定した日から適用される規則を作成します.
+    pub const fn since(law: &'static Law, from: NaiveDate, rule: DateRule) -> Period {
+        Period {
+            law,
+            from,
+            until: None,
+            rule: Some(rule),
+        }
+    }
+
+    /// 指定した期間(両端を含む)のみ適用される特例を作成します.
+    pub const fn only(
+        law: &'static Law,
+        from: NaiveDate,
+        until: NaiveDate,
+        rule: DateRule,
+    ) -> Period {
+        Period {
+            law,
+            from,
+            until: Some(until),
+            rule: Some(rule),
+        }
+    }
+
+    /// 指定した日に一度限り適用される規則を作成します.
+    pub const fn once(law: &'static Law, date: NaiveDate) -> Period {
+        Period::only(law, date, date, DateRule::Date(date))
+    }
+
+    /// 指定した日から祝日を廃止する規則を作成します.
+    pub const fn abolished(law: &'static Law, from: NaiveDate) -> Period {
+        Period {
+            law,
+            from,
+            until: None,
+            rule: None,
+        }
+    }
+
+    /// 指定した日付がこの期間に含まれるかどうかを判定します.
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.from <= *date && !matches!(self.until, Some(until) if until < *date)
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rule {
+            Some(rule) => write!(f, "{rule}")?,
+            None => write!(f, "廃止")?,
+        }
+        write!(
+            f,
+            " ({}, {}から",
+            self.law.number,
+            self.from.format("%Y年%-m月%-d日")
+        )?;
+        if let Some(until) = self.until {
+            write!(f, "{}まで", until.format("%Y年%-m月%-d日"))?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// 指定した日付に適用される期間を取得します.
+///
+/// 該当する期間が複数ある場合は, 最も新しく公布された法令の期間を返します.
+/// 公布日が同じ場合は後に定義された期間を返します.
+///
+/// # Arguments
+///
+/// * `periods` - 期間の集合
+/// * `date` - 日付
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::law::period_on;
+/// use yasumi::public_holiday::MarineDay;
+/// use yasumi::PublicHoliday;
+///
+/// let date = NaiveDate::from_ymd_opt(2020, 7, 23).unwrap();
+/// let period = period_on(MarineDay.periods(), &date).unwrap();
+/// assert_eq!(period.law.number, "平成30年法律第55号");
+/// ```
+pub fn period_on<'a>(periods: &'a [Period], date: &NaiveDate) -> Option<&'a Period> {
+    period_as_known_on(periods, date, &NaiveDate::MAX)
+}
+
+/// 指定した時点で公布されていた法令だけに従って, 指定した日付に適用される期間を取得します.
+///
+/// 後から公布された法令による改正や特例は考慮しません.
+///
+/// # Arguments
+///
+/// * `periods` - 期間の集合
+/// * `date` - 日付
+/// * `known_on` - 法令を参照する時点
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::law::period_as_known_on;
+/// use yasumi::public_holiday::MarineDay;
+/// use yasumi::PublicHoliday;
+///
+/// // 2021年の海の日を7月22日に移す特例は2020年12月4日に公布された
+/// let date = NaiveDate::from_ymd_opt(2021, 7, 22).unwrap();
+/// let known_on = NaiveDate::from_ymd_opt(2020, 6, 1).unwrap();
+/// let period = period_as_known_on(MarineDay.periods(), &date, &known_on).unwrap();
+/// assert_eq!(period.law.number, "平成13年法律第59号");
+/// ```
+pub fn period_as_known_on<'a>(
+    periods: &'a [Period],
+    date: &NaiveDate,
+    known_on: &NaiveDate,
+) -> Option<&'a Period> {
+    periods
+        .iter()
+        .filter(|period| period.law.promulgated <= *known_on && period.contains(date))
+        .max_by_key(|period| period.law.promulgated)
+}
+
+/// 指定した期間の集合に従って, 指定した日付が祝日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `periods` - 期間の集合
+/// * `date` - 日付
+pub fn is_holiday_in(periods: &[Period], date: &NaiveDate) -> bool {
+    is_holiday_as_known_on(periods, date, &NaiveDate::MAX)
+}
+
+/// 指定した時点で公布されていた法令だけに従って, 指定した日付が祝日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `periods` - 期間の集合
+/// * `date` - 日付
+/// * `known_on` - 法令を参照する時点
+pub fn is_holiday_as_known_on(periods: &[Period], date: &NaiveDate, known_on: &NaiveDate) -> bool {
+    period_as_known_on(periods, date, known_on)
+        .and_then(|period| period.rule)
+        .is_some_and(|rule| rule.matches(date))
+}
+
+/// 指定した時点で公布されていた法令だけに従って, 祝日になりうる日付の範囲を取得します.
+///
+/// 後に公布された法令で廃止された期間は廃止の前日までとします.
+///
+/// # Arguments
+///
+/// * `periods` - 期間の集合
+/// * `known_on` - 法令を参照する時点
+///
+/// # Returns
+///
+/// 日付の範囲を返します. 祝日になりうる期間がない場合は `None` を返します.
+pub fn possible_dates_as_known_on(
+    periods: &[Period],
+    known_on: &NaiveDate,
+) -> Option<RangeInclusive<NaiveDate>> {
+    let known = || {
+        periods
+            .iter()
+            .filter(|period| period.law.promulgated <= *known_on)
+    };
+    let start = known()
+        .filter(|period| period.rule.is_some())
+        .map(|period| period.from)
+        .min()?;
+    let end = known()
+        .filter(|period| period.rule.is_some())
+        .map(|period| {
+            period.until.unwrap_or_else(|| {
+                known()
+                    .filter(|abolished| {
+                        abolished.rule.is_none()
+                            && abolished.from > period.from
+                            && abolished.law.promulgated >= period.law.promulgated
+                    })
+                    .filter_map(|abolished| abolished.from.pred_opt())
+                    .min()
+                    .unwrap_or(NaiveDate::MAX)
+            })
+        })
+        .max()?;
+    Some(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_period_on_prefers_latest_law() {
+        let periods = [
+            Period::since(
+                &HEISEI_13_LAW_59,
+                ymd(2003, 1, 1),
+                DateRule::NthWeekday {
+                    month: 7,
+                    week: 3,
+                    weekday: Weekday::Mon,
+                },
+            ),
+            Period::only(
+                &HEISEI_30_LAW_55,
+                ymd(2020, 1, 1),
+                ymd(2020, 12, 31),
+                DateRule::Date(ymd(2020, 7, 23)),
+            ),
+        ];
+        assert!(is_holiday_in(&periods, &ymd(2019, 7, 15)));
+        assert!(!is_holiday_in(&periods, &ymd(2020, 7, 20)));
+        assert!(is_holiday_in(&periods, &ymd(2020, 7, 23)));
+        assert!(is_holiday_in(&periods, &ymd(2021, 7, 19)));
+        assert!(!is_holiday_in(&periods, &ymd(2002, 7, 15)));
+
+        // 特例の公布前は通常の規則に従う
+        assert!(is_holiday_as_known_on(
+            &periods,
+            &ymd(2020, 7, 20),
+            &ymd(2018, 6, 19)
+        ));
+        assert!(!is_holiday_as_known_on(
+            &periods,
+            &ymd(2020, 7, 23),
+            &ymd(2018, 6, 19)
+        ));
+        assert!(is_holiday_as_known_on(
+            &periods,
+            &ymd(2020, 7, 23),
+            &ymd(2018, 6, 20)
+        ));
+        assert!(!is_holiday_as_known_on(
+            &periods,
+            &ymd(2019, 7, 15),
+            &ymd(2001, 6, 21)
+        ));
+    }
+
+    #[test]
+    fn test_possible_dates_as_known_on() {
+        let periods = [
+            Period::since(
+                &MEIJI_6_DECREE_344,
+                ymd(1873, 10, 14),
+                DateRule::Fixed { month: 2, day: 11 },
+            ),
+            Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+        ];
+        assert_eq!(
+            possible_dates_as_known_on(&periods, &NaiveDate::MAX),
+            Some(ymd(1873, 10, 14)..=ymd(1948, 7, 19))
+        );
+        // 廃止の公布前は期限がない
+        assert_eq!(
+            possible_dates_as_known_on(&periods, &ymd(1940, 1, 1)),
+            Some(ymd(1873, 10, 14)..=NaiveDate::MAX)
+        );
+        assert_eq!(possible_dates_as_known_on(&periods, &ymd(1870, 1, 1)), None);
+        assert_eq!(
+            possible_dates_as_known_on(
+                &[Period::once(&HEISEI_30_LAW_55, ymd(2020, 7, 23))],
+                &NaiveDate::MAX
+            ),
+            Some(ymd(2020, 7, 23)..=ymd(2020, 7, 23))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let period = Period::since(
+            &HEISEI_13_LAW_59,
+            ymd(2003, 1, 1),
+            DateRule::NthWeekday {
+                month: 7,
+                week: 3,
+                weekday: Weekday::Mon,
+            },
+        );
+        assert_eq!(
+            period.to_string(),
+            "7月の第3月曜日 (平成13年法律第59号, 2003年1月1日から)"
+        );
+        assert_eq!(
+            Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)).to_string(),
+            "廃止 (昭和23年法律第178号, 1948年7月20日から)"
+        );
+        assert_eq!(
+            SHOWA_23_LAW_178.to_string(),
+            "国民の祝日に関する法律(昭和23年法律第178号)"
+        );
+    }
+}
@@ -1,37 +1,59 @@
-mod datelike;
-mod public_holiday;
+mod bank;
+mod business_day;
+mod calendar;
+mod convention;
+pub mod datelike;
+mod days_off;
+mod equinox;
+mod error;
+mod government;
+mod holiday;
+mod iter;
+pub mod law;
+mod locale;
+mod market;
+pub mod public_holiday;
+mod search;
+#[cfg(feature = "static-table")]
+mod static_table;
+mod weekend;
+mod year_table;
 
-use chrono::{Datelike, NaiveDate};
-use datelike::DateLike;
-use public_holiday::{substitute_holiday, HOLIDAYS};
+use std::ops::RangeBounds;
+use std::sync::OnceLock;
 
-/// 「国民の休日」を含めない祝日を判定します.
-///
-/// # Arguments
-///
-/// * `date` - 日付
-///
-/// # Returns
-///
-/// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
-/// ただし, 「国民の休日」は含まれません.
-fn calc_holiday_without_national_holiday<T: DateLike>(date: T) -> Option<String> {
-    let date = date.date()?;
+pub use bank::{
+    is_bank_holiday, next_bank_business_day, previous_bank_business_day, settlement_date,
+};
+use calendar::into_pairs;
+pub use calendar::{
+    FixedDateHoliday, HolidayCalendar, HolidayCalendarBuilder, NthWeekdayHoliday,
+    SpecificDateHoliday,
+};
+use chrono::NaiveDate;
+pub use convention::BusinessDayConvention;
+pub use datelike::DateLike;
+pub use days_off::DaysOff;
+pub use equinox::{autumnal_equinox, vernal_equinox};
+pub use error::YasumiError;
+pub use government::{is_government_closed, statutory_deadline};
+pub use holiday::{Holiday, HolidayId, HolidayKind, HolidayStatus};
+pub use iter::HolidayIter;
+pub use locale::{default_locale, set_default_locale, Locale};
+pub use market::{
+    add_trading_days, is_trading_day, next_trading_day, previous_trading_day, trading_days_between,
+};
+pub use public_holiday::{is_supported_year, supported_years, PublicHoliday};
+pub use search::Inclusion;
+pub use weekend::Weekend;
 
-    for holiday in HOLIDAYS.iter() {
-        if holiday.is_holiday(&date) {
-            return Some(holiday.name());
-        }
-    }
-
-    // 振替休日
-    if let Some(substitute) = substitute_holiday(&date) {
-        return Some(substitute);
-    }
-    None
+/// 国民の祝日のカレンダーを取得します.
+fn national_calendar() -> &'static HolidayCalendar {
+    static NATIONAL_CALENDAR: OnceLock<HolidayCalendar> = OnceLock::new();
+    NATIONAL_CALENDAR.get_or_init(HolidayCalendar::national)
 }
 
-/// 祝日を判定します.
+/// 指定した日付の祝日を取得します.
 ///
 /// # Arguments
 ///
@@ -39,29 +61,19 @@ fn calc_holiday_without_national_holiday<T: DateLike>(date: T) -> Option<String>
 ///
 /// # Returns
 ///
-/// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
-fn calc_holiday<T: DateLike>(date: T) -> Option<String> {
-    let date = date.date()?;
-
-    if let Some(holiday_name) = calc_holiday_without_national_holiday(date) {
-        return Some(holiday_name);
-    }
-
-    // 日曜日の場合は国民の休日ではない
-    if date.weekday().number_from_monday() == 7 {
-        return None;
-    }
-    // 次の日と前の日が祝日の場合
-    let next_day = date.succ_opt()?;
-    let prev_day = date.pred_opt()?;
-    let next_day_holiday = calc_holiday_without_national_holiday(next_day);
-    let prev_day_holiday = calc_holiday_without_national_holiday(prev_day);
-
-    if next_day_holiday.is_some() && prev_day_holiday.is_some() {
-        return Some("国民の休日".to_string());
-    }
-
-    None
+/// 祝日の場合は祝日を返します. それ以外の場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{holiday_detail, HolidayId, HolidayKind};
+///
+/// let holiday = holiday_detail("2024-02-12").unwrap();
+/// assert_eq!(holiday.id, HolidayId::SubstituteHoliday);
+/// assert_eq!(holiday.kind, HolidayKind::Substitute);
+/// assert_eq!(holiday.substitute_for, Some(HolidayId::NationalFoundationDay));
+/// ```
+pub fn holiday_detail<T: DateLike>(date: T) -> Option<Holiday> {
+    national_calendar().holiday_detail(date)
 }
 
 /// 指定した日付の祝日名を取得します.
@@ -82,8 +94,7 @@ fn calc_holiday<T: DateLike>(date: T) -> Option<String> {
 /// assert_eq!(holiday, Some("元日".to_string()));
 /// ```
 pub fn is_holiday_name<T: DateLike>(date: T) -> Option<String> {
-    let date = date.date()?;
-    calc_holiday(date)
+    holiday_detail(date).map(|holiday| holiday.name)
 }
 
 /// 指定した日付の祝日名を取得します.
@@ -108,6 +119,28 @@ pub fn holiday_name<T: DateLike>(date: T) -> Option<String> {
     is_holiday_name(date)
 }
 
+/// 指定した日付の祝日名を指定した言語で取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `locale` - 言語
+///
+/// # Returns
+///
+/// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{holiday_name_in, Locale};
+///
+/// let holiday = holiday_name_in("2024-01-08", Locale::En);
+/// assert_eq!(holiday, Some("Coming of Age Day".to_string()));
+/// ```
+pub fn holiday_name_in<T: DateLike>(date: T, locale: Locale) -> Option<String> {
+    holiday_detail(date).map(|holiday| holiday.name_in(locale))
+}
+
 /// 指定した日付が祝日かどうかを判定します.
 ///
 /// # Arguments
@@ -138,6 +171,7 @@ pub fn is_holiday<T: DateLike>(date: T) -> bool {
 /// # Returns
 ///
 /// 土日祝の場合は `true` を返します. それ以外の場合は `false` を返します.
+/// 日付が不正な場合は `false` を返します.
 ///
 /// # Examples
 /// ```
@@ -147,11 +181,199 @@ pub fn is_holiday<T: DateLike>(date: T) -> bool {
 /// assert_eq!(no_workday, true);
 /// ```
 pub fn is_no_workday<T: DateLike>(date: T) -> bool {
-    let date = date.date().unwrap();
-    if date.weekday().number_from_monday() == 6 || date.weekday().number_from_monday() == 7 {
-        return true;
-    }
-    is_holiday(date)
+    national_calendar().is_no_workday(date)
+}
+
+/// 指定した日付が営業日(土日祝以外)かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 営業日の場合は `true` を返します. それ以外の場合は `false` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::is_business_day;
+///
+/// assert_eq!(is_business_day("2024-09-16"), false); // 敬老の日
+/// assert_eq!(is_business_day("2024-09-17"), true);
+/// ```
+pub fn is_business_day<T: DateLike>(date: T) -> bool {
+    national_calendar().is_business_day(date)
+}
+
+/// 指定した日付から指定した営業日数後の日付を取得します.
+/// 指定した日付は数えません.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `days` - 営業日数
+///
+/// # Returns
+///
+/// 営業日数後の日付を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::add_business_days;
+///
+/// let date = add_business_days("2024-09-13", 2);
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 9, 18));
+/// ```
+pub fn add_business_days<T: DateLike>(date: T, days: u32) -> Option<NaiveDate> {
+    national_calendar().add_business_days(date, days)
+}
+
+/// 指定した日付から指定した営業日数前の日付を取得します.
+/// 指定した日付は数えません.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `days` - 営業日数
+///
+/// # Returns
+///
+/// 営業日数前の日付を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::sub_business_days;
+///
+/// let date = sub_business_days("2024-09-18", 2);
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 9, 13));
+/// ```
+pub fn sub_business_days<T: DateLike>(date: T, days: u32) -> Option<NaiveDate> {
+    national_calendar().sub_business_days(date, days)
+}
+
+/// 指定した日付の翌営業日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 翌営業日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::next_business_day;
+///
+/// let date = next_business_day("2024-09-13");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 9, 17));
+/// ```
+pub fn next_business_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    national_calendar().next_business_day(date)
+}
+
+/// 指定した日付の前営業日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 前営業日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::previous_business_day;
+///
+/// let date = previous_business_day("2024-09-17");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 9, 13));
+/// ```
+pub fn previous_business_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    national_calendar().previous_business_day(date)
+}
+
+/// 指定した期間の営業日数を取得します.
+/// 開始日は含まず, 終了日は含みます.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含まない)
+/// * `end_date` - 終了日(含む)
+///
+/// # Returns
+///
+/// 営業日数を返します. 開始日が終了日より後の場合は負の値を返します.
+/// 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::business_days_between;
+///
+/// let days = business_days_between("2024-09-13", "2024-09-20");
+/// assert_eq!(days, Some(4));
+/// ```
+pub fn business_days_between<T: DateLike>(start_date: T, end_date: T) -> Option<i64> {
+    national_calendar().business_days_between(start_date, end_date)
+}
+
+/// 指定した日付が非営業日(土日祝)の場合に, 指定した方法で営業日に調整します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `convention` - 営業日調整の方法
+///
+/// # Returns
+///
+/// 調整後の日付を返します. 指定した日付が営業日の場合はそのまま返します.
+/// 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::{adjust, BusinessDayConvention};
+///
+/// // 2024年8月31日(土)
+/// let date = adjust("2024-08-31", BusinessDayConvention::ModifiedFollowing);
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 8, 30));
+/// ```
+pub fn adjust<T: DateLike>(date: T, convention: BusinessDayConvention) -> Option<NaiveDate> {
+    national_calendar().adjust(date, convention)
+}
+
+/// 指定した日付から指定した月数後の日付を, 指定した方法で営業日に調整します.
+///
+/// `ModifiedFollowingEndOfMonth` では, 月の最終営業日を基準とした日付を移動先の月の最終営業日に調整します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `months` - 月数. 負の値の場合は前の月に移動します
+/// * `convention` - 営業日調整の方法
+///
+/// # Returns
+///
+/// 調整後の日付を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::{add_months, BusinessDayConvention};
+///
+/// let date = add_months("2024-09-30", 1, BusinessDayConvention::ModifiedFollowingEndOfMonth);
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 10, 31));
+/// ```
+pub fn add_months<T: DateLike>(
+    date: T,
+    months: i32,
+    convention: BusinessDayConvention,
+) -> Option<NaiveDate> {
+    national_calendar().add_months(date, months, convention)
 }
 
 /// 指定した年月の祝日を取得します.
@@ -174,18 +396,31 @@ pub fn is_no_workday<T: DateLike>(date: T) -> bool {
 /// assert_eq!(holidays.len(), 2);
 /// ```
 pub fn month_holidays(year: i32, month: u32) -> Vec<(NaiveDate, String)> {
-    let mut holidays = vec![];
-
-    for day in 1..=31 {
-        let date = NaiveDate::from_ymd_opt(year, month, day);
-        if let Some(date) = date {
-            if let Some(name) = is_holiday_name(date) {
-                holidays.push((date, name));
-            }
-        }
-    }
+    into_pairs(month_holidays_detail(year, month))
+}
 
-    holidays
+/// 指定した年月の祝日を取得します.
+/// 祝日が存在しない場合は空のリストを返します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+/// * `month` - 月
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{month_holidays_detail, HolidayId};
+///
+/// let holidays = month_holidays_detail(2024, 1);
+/// assert_eq!(holidays[0].id, HolidayId::NewYearsDay);
+/// assert_eq!(holidays[1].id, HolidayId::ComingOfAgeDay);
+/// ```
+pub fn month_holidays_detail(year: i32, month: u32) -> Vec<Holiday> {
+    national_calendar().month_holidays_detail(year, month)
 }
 
 /// 指定した年の祝日を取得します.
@@ -207,20 +442,33 @@ pub fn month_holidays(year: i32, month: u32) -> Vec<(NaiveDate, String)> {
 /// assert_eq!(holidays.len(), 21);
 /// ```
 pub fn year_holidays(year: i32) -> Vec<(NaiveDate, String)> {
-    let mut holidays = vec![];
-
-    for month in 1..=12 {
-        for day in 1..=31 {
-            let date = NaiveDate::from_ymd_opt(year, month, day);
-            if let Some(date) = date {
-                if let Some(name) = is_holiday_name(date) {
-                    holidays.push((date, name));
-                }
-            }
-        }
-    }
+    into_pairs(year_holidays_detail(year))
+}
 
-    holidays
+/// 指定した年の祝日を取得します.
+/// 祝日が存在しない場合は空のリストを返します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{year_holidays_detail, HolidayKind};
+///
+/// let holidays = year_holidays_detail(2024);
+/// let substitutes = holidays
+///     .iter()
+///     .filter(|holiday| holiday.kind == HolidayKind::Substitute)
+///     .count();
+/// assert_eq!(substitutes, 5);
+/// ```
+pub fn year_holidays_detail(year: i32) -> Vec<Holiday> {
+    national_calendar().year_holidays_detail(year)
 }
 
 /// 指定した期間の祝日を取得します.
@@ -257,7 +505,7 @@ pub fn holidays<T: DateLike>(start_date: T, end_date: T) -> Vec<(NaiveDate, Stri
 ///
 /// # Returns
 ///
-/// 祝日のリストを返します.
+/// 祝日のリストを返します. 日付が不正な場合は空のリストを返します.
 ///
 /// # Examples
 /// ```
@@ -267,20 +515,437 @@ pub fn holidays<T: DateLike>(start_date: T, end_date: T) -> Vec<(NaiveDate, Stri
 /// assert_eq!(holidays.len(), 21);
 /// ```
 pub fn between<T: DateLike>(start_date: T, end_date: T) -> Vec<(NaiveDate, String)> {
-    let start_date = start_date.date().unwrap();
-    let end_date = end_date.date().unwrap();
+    into_pairs(between_detail(start_date, end_date))
+}
+
+/// 指定した期間の祝日を取得します.
+/// 祝日が存在しない場合は空のリストを返します.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含む)
+/// * `end_date` - 終了日(含む)
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{between_detail, HolidayId};
+///
+/// let holidays = between_detail("2024-05-01", "2024-05-31");
+/// assert_eq!(holidays.len(), 4);
+/// assert_eq!(holidays[3].substitute_for, Some(HolidayId::ChildrensDay));
+/// ```
+pub fn between_detail<T: DateLike>(start_date: T, end_date: T) -> Vec<Holiday> {
+    national_calendar().between_detail(start_date, end_date)
+}
 
-    let mut holidays = vec![];
+/// 指定した期間の祝日を日付順に返すイテレータを作成します.
+///
+/// # Arguments
+///
+/// * `range` - 期間. `start..`, `..=end`, `start..end` などの形で指定します
+///
+/// # Returns
+///
+/// 祝日のイテレータを返します. 逆順にも辿れます.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::holiday_iter;
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+/// let dates: Vec<NaiveDate> = holiday_iter(today..).take(2).map(|holiday| holiday.date).collect();
+/// assert_eq!(
+///     dates,
+///     vec![
+///         NaiveDate::from_ymd_opt(2024, 9, 16).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 9, 22).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn holiday_iter<R: RangeBounds<NaiveDate>>(range: R) -> HolidayIter<'static> {
+    national_calendar().holiday_iter(range)
+}
 
-    let mut date = start_date;
-    while date <= end_date {
-        if let Some(name) = is_holiday_name(date) {
-            holidays.push((date, name));
-        }
-        date = date.succ_opt().unwrap();
-    }
+/// 指定した日付以降で最初の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `inclusion` - 指定した日付自体を対象に含めるかどうか
+///
+/// # Returns
+///
+/// 祝日の日付と名前を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::{next_holiday, Inclusion};
+///
+/// let holiday = next_holiday("2024-09-16", Inclusion::Exclusive);
+/// assert_eq!(
+///     holiday,
+///     Some((NaiveDate::from_ymd_opt(2024, 9, 22).unwrap(), "秋分の日".to_string()))
+/// );
+/// ```
+pub fn next_holiday<T: DateLike>(date: T, inclusion: Inclusion) -> Option<(NaiveDate, String)> {
+    national_calendar().next_holiday(date, inclusion)
+}
+
+/// 指定した日付以前で最後の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `inclusion` - 指定した日付自体を対象に含めるかどうか
+///
+/// # Returns
+///
+/// 祝日の日付と名前を返します. 日付が不正な場合や祝日が存在しない場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::{previous_holiday, Inclusion};
+///
+/// let holiday = previous_holiday("2024-09-16", Inclusion::Inclusive);
+/// assert_eq!(
+///     holiday,
+///     Some((NaiveDate::from_ymd_opt(2024, 9, 16).unwrap(), "敬老の日".to_string()))
+/// );
+/// ```
+pub fn previous_holiday<T: DateLike>(date: T, inclusion: Inclusion) -> Option<(NaiveDate, String)> {
+    national_calendar().previous_holiday(date, inclusion)
+}
+
+/// 指定した日付より後で `n` 番目の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付(含まない)
+/// * `n` - 何番目か(1から数える)
+///
+/// # Returns
+///
+/// 祝日の日付と名前を返します. 日付が不正な場合や `n` が `0` の場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::nth_holiday_after;
+///
+/// let holiday = nth_holiday_after("2024-04-28", 4);
+/// assert_eq!(
+///     holiday,
+///     Some((NaiveDate::from_ymd_opt(2024, 5, 5).unwrap(), "こどもの日".to_string()))
+/// );
+/// ```
+pub fn nth_holiday_after<T: DateLike>(date: T, n: usize) -> Option<(NaiveDate, String)> {
+    national_calendar().nth_holiday_after(date, n)
+}
+
+/// 指定した期間にかかる連休を取得します.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含む)
+/// * `end_date` - 終了日(含む)
+/// * `min_days` - 最小の日数. これより短い連休は返しません
+///
+/// # Returns
+///
+/// 連休のリストを日付順に返します. 期間の両端にかかる連休は期間の外まで延ばして返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::consecutive_days_off;
+///
+/// // 2019年のゴールデンウィーク
+/// let runs = consecutive_days_off("2019-05-01", "2019-05-01", 1);
+/// assert_eq!(runs[0].start, NaiveDate::from_ymd_opt(2019, 4, 27).unwrap());
+/// assert_eq!(runs[0].end, NaiveDate::from_ymd_opt(2019, 5, 6).unwrap());
+/// assert_eq!(runs[0].days, 10);
+/// ```
+pub fn consecutive_days_off<T: DateLike>(
+    start_date: T,
+    end_date: T,
+    min_days: u32,
+) -> Vec<DaysOff> {
+    national_calendar().consecutive_days_off(start_date, end_date, min_days)
+}
+
+/// 指定した年に始まる3日以上の連休を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+///
+/// # Returns
+///
+/// 連休のリストを日付順に返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::long_weekends;
+///
+/// let runs = long_weekends(2015);
+/// let silver_week = runs.iter().max_by_key(|run| run.days).unwrap();
+/// assert_eq!(silver_week.days, 5);
+/// assert_eq!(silver_week.holidays.len(), 3);
+/// ```
+pub fn long_weekends(year: i32) -> Vec<DaysOff> {
+    national_calendar().long_weekends(year)
+}
+
+/// 指定した年に始まる, 指定した日数以上の連休を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+/// * `min_days` - 最小の日数. これより短い連休は返しません
+///
+/// # Returns
+///
+/// 連休のリストを日付順に返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::long_weekends_with;
+///
+/// // 2019年の5日以上の連休はゴールデンウィークのみ
+/// let runs = long_weekends_with(2019, 5);
+/// assert_eq!(runs.len(), 1);
+/// assert_eq!(runs[0].days, 10);
+/// ```
+pub fn long_weekends_with(year: i32, min_days: u32) -> Vec<DaysOff> {
+    national_calendar().long_weekends_with(year, min_days)
+}
+
+/// 指定した日付の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 祝日の場合は祝日を返します. それ以外の場合は `None` を返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{try_holiday_detail, HolidayId, YasumiError};
+///
+/// let holiday = try_holiday_detail("2024-01-01").unwrap().unwrap();
+/// assert_eq!(holiday.id, HolidayId::NewYearsDay);
+///
+/// let error = try_holiday_detail("2024-13-01").unwrap_err();
+/// assert_eq!(error, YasumiError::InvalidDate(Some("2024-13-01".to_string())));
+///
+/// let error = try_holiday_detail("1800-01-01").unwrap_err();
+/// assert_eq!(error, YasumiError::UnsupportedYear(1800));
+/// ```
+pub fn try_holiday_detail<T: DateLike>(date: T) -> Result<Option<Holiday>, YasumiError> {
+    national_calendar().try_holiday_detail(date)
+}
+
+/// 指定した日付の祝日名を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 祝日の場合は祝日名を返します. それ以外の場合は `None` を返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::try_holiday_name;
+///
+/// assert_eq!(try_holiday_name("2024-01-01"), Ok(Some("元日".to_string())));
+/// assert!(try_holiday_name("2024-13-01").is_err());
+/// ```
+pub fn try_holiday_name<T: DateLike>(date: T) -> Result<Option<String>, YasumiError> {
+    national_calendar().try_holiday_name(date)
+}
+
+/// 指定した日付が祝日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 祝日の場合は `true` を返します. それ以外の場合は `false` を返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::try_is_holiday;
+///
+/// assert_eq!(try_is_holiday("2024-01-01"), Ok(true));
+/// assert!(try_is_holiday("2024-13-01").is_err());
+/// ```
+pub fn try_is_holiday<T: DateLike>(date: T) -> Result<bool, YasumiError> {
+    national_calendar().try_is_holiday(date)
+}
+
+/// 指定した日付が土日祝かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 土日祝の場合は `true` を返します. それ以外の場合は `false` を返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::try_is_no_workday;
+///
+/// assert_eq!(try_is_no_workday("2024-09-15"), Ok(true));
+/// assert!(try_is_no_workday("2024-13-01").is_err());
+/// ```
+pub fn try_is_no_workday<T: DateLike>(date: T) -> Result<bool, YasumiError> {
+    national_calendar().try_is_no_workday(date)
+}
 
-    holidays
+/// 指定した年月の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+/// * `month` - 月
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+/// 月が存在しない場合は `YasumiError::InvalidMonth` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::{try_month_holidays, YasumiError};
+///
+/// assert_eq!(try_month_holidays(2024, 1).unwrap().len(), 2);
+/// assert_eq!(try_month_holidays(2024, 13), Err(YasumiError::InvalidMonth(13)));
+/// ```
+pub fn try_month_holidays(year: i32, month: u32) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+    national_calendar().try_month_holidays(year, month)
+}
+
+/// 指定した年月の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+/// * `month` - 月
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+/// 月が存在しない場合は `YasumiError::InvalidMonth` を返します.
+pub fn try_month_holidays_detail(year: i32, month: u32) -> Result<Vec<Holiday>, YasumiError> {
+    national_calendar().try_month_holidays_detail(year, month)
+}
+
+/// 指定した年の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::try_year_holidays;
+///
+/// assert_eq!(try_year_holidays(2024).unwrap().len(), 21);
+/// ```
+pub fn try_year_holidays(year: i32) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+    national_calendar().try_year_holidays(year)
+}
+
+/// 指定した年の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を返します.
+pub fn try_year_holidays_detail(year: i32) -> Result<Vec<Holiday>, YasumiError> {
+    national_calendar().try_year_holidays_detail(year)
+}
+
+/// 指定した期間の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含む)
+/// * `end_date` - 終了日(含む)
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+/// 開始日が終了日より後の場合は `YasumiError::InvalidRange` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::try_between;
+///
+/// assert_eq!(try_between("2024-01-01", "2024-12-31").unwrap().len(), 21);
+/// assert!(try_between("2024-12-31", "2024-01-01").is_err());
+/// ```
+pub fn try_between<T: DateLike>(
+    start_date: T,
+    end_date: T,
+) -> Result<Vec<(NaiveDate, String)>, YasumiError> {
+    national_calendar().try_between(start_date, end_date)
+}
+
+/// 指定した期間の祝日を取得します.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含む)
+/// * `end_date` - 終了日(含む)
+///
+/// # Returns
+///
+/// 祝日のリストを返します.
+/// 日付が不正な場合は `YasumiError::InvalidDate` を,
+/// 年に対応していない場合は `YasumiError::UnsupportedYear` を,
+/// 開始日が終了日より後の場合は `YasumiError::InvalidRange` を返します.
+pub fn try_between_detail<T: DateLike>(
+    start_date: T,
+    end_date: T,
+) -> Result<Vec<Holiday>, YasumiError> {
+    national_calendar().try_between_detail(start_date, end_date)
 }
 
 #[cfg(test)]
@@ -300,6 +965,219 @@ mod tests {
         assert_eq!(is_no_workday(date), expected);
     }
 
+    #[rstest]
+    #[case("2024-13-01")]
+    #[case("2024/02/30")]
+    #[case("")]
+    fn test_invalid_date_does_not_panic(#[case] date: &str) {
+        assert!(!is_no_workday(date));
+        assert_eq!(is_holiday_name(date), None);
+        assert_eq!(between(date, "2024-12-31"), vec![]);
+        assert_eq!(
+            try_is_no_workday(date),
+            Err(YasumiError::InvalidDate(Some(date.to_string())))
+        );
+        assert_eq!(
+            try_between(date, "2024-12-31"),
+            Err(YasumiError::InvalidDate(Some(date.to_string())))
+        );
+    }
+
+    #[test]
+    fn test_try_functions() {
+        assert_eq!(
+            try_year_holidays(i32::MAX),
+            Err(YasumiError::UnsupportedYear(i32::MAX))
+        );
+        assert_eq!(
+            try_month_holidays(2024, 0),
+            Err(YasumiError::InvalidMonth(0))
+        );
+        assert_eq!(
+            try_between("2024-12-31", "2024-01-01"),
+            Err(YasumiError::InvalidRange {
+                start: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            })
+        );
+        assert_eq!(
+            try_between("2024-01-01", "2024-12-31"),
+            Ok(between("2024-01-01", "2024-12-31"))
+        );
+    }
+
+    #[rstest]
+    #[case("1874/01/30", Some("孝明天皇祭"))]
+    #[case("1878/09/17", Some("神嘗祭"))]
+    #[case("1879/10/17", Some("神嘗祭"))]
+    #[case("1879/09/17", None)]
+    #[case("1900/11/03", Some("天長節"))]
+    #[case("1900/05/03", None)] // 憲法記念日は1948年から
+    #[case("1900/01/01", None)] // 四方拝は1927年から
+    #[case("1900/01/04", None)] // 国民の休日は存在しない
+    #[case("1912/01/30", Some("孝明天皇祭"))]
+    #[case("1912/11/03", None)]
+    #[case("1915/08/31", Some("天長節"))]
+    #[case("1915/10/31", Some("天長節祝日"))]
+    #[case("1920/07/30", Some("明治天皇祭"))]
+    #[case("1927/01/01", None)]
+    #[case("1928/01/01", Some("四方拝"))]
+    #[case("1930/04/29", Some("天長節"))]
+    #[case("1930/11/03", Some("明治節"))]
+    #[case("1930/12/25", Some("大正天皇祭"))]
+    #[case("1947/05/03", None)]
+    #[case("1948/04/29", Some("天長節"))]
+    #[case("1948/07/19", None)]
+    #[case("1966/09/15", Some("敬老の日"))] // 1966年6月25日施行
+    #[case("1967/02/11", Some("建国記念の日"))]
+    #[case("1966/02/11", None)]
+    #[case("1973/02/12", None)] // 振替休日は1973年4月12日から
+    #[case("1973/04/30", Some("天皇誕生日 振替休日"))]
+    #[case("1973/04/11", None)]
+    #[case("1985/05/06", Some("こどもの日 振替休日"))]
+    #[case("1985/05/04", None)]
+    #[case("1988/05/04", Some("国民の休日"))]
+    #[case("1989/04/29", Some("みどりの日"))] // 1989年2月17日施行
+    #[case("1992/05/04", Some("憲法記念日 振替休日"))] // 2006年までは翌日に振り替える
+    #[case("2006/01/02", Some("元日 振替休日"))]
+    #[case("2008/05/06", Some("みどりの日 振替休日"))] // 2007年からは祝日でない日に振り替える
+    #[case("2009/05/06", Some("憲法記念日 振替休日"))]
+    #[case("2019/02/23", None)]
+    #[case("2019/12/23", None)] // 2019年4月30日施行
+    #[case("2020/02/23", Some("天皇誕生日"))]
+    #[case("1949/04/29", Some("天皇誕生日"))]
+    fn test_effective_dates(#[case] date: &str, #[case] expected: Option<&str>) {
+        let date = NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap();
+        assert_eq!(holiday_name(date).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("2024/01/01", HolidayStatus::Statutory)]
+    #[case("2025/03/20", HolidayStatus::Announced)]
+    #[case("2027/09/23", HolidayStatus::Announced)]
+    #[case("2028/03/20", HolidayStatus::Projected)]
+    #[case("2032/09/21", HolidayStatus::Projected)] // 国民の休日(秋分の日の前日)
+    #[case("2033/03/21", HolidayStatus::Projected)] // 振替休日(春分の日が日曜日)
+    #[case("2024/09/23", HolidayStatus::Announced)] // 振替休日(秋分の日が日曜日)
+    #[case("1930/03/21", HolidayStatus::Projected)] // 春季皇霊祭
+    #[case("1930/09/24", HolidayStatus::Projected)] // 秋季皇霊祭
+    fn test_holiday_status(#[case] date: &str, #[case] expected: HolidayStatus) {
+        let date = NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap();
+        assert_eq!(holiday_detail(date).unwrap().status, expected);
+    }
+
+    #[rstest]
+    #[case("2024/07/15", Some("平成13年法律第59号"))] // 海の日(7月の第3月曜日)
+    #[case("2020/07/23", Some("平成30年法律第55号"))] // 海の日(東京オリンピックの特例)
+    #[case("2019/05/01", Some("平成30年法律第99号"))] // 天皇の即位の日
+    #[case("1930/11/03", Some("昭和2年勅令第25号"))] // 明治節
+    #[case("1985/05/06", Some("昭和48年法律第10号"))] // 振替休日
+    #[case("2024/02/12", Some("平成17年法律第43号"))] // 振替休日
+    #[case("1988/05/04", Some("昭和60年法律第103号"))] // 国民の休日
+    #[case("2024/01/02", None)]
+    fn test_legal_basis(#[case] date: &str, #[case] expected: Option<&str>) {
+        let date = NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap();
+        assert_eq!(
+            holiday_detail(date)
+                .and_then(|holiday| holiday.legal_basis)
+                .map(|period| period.law.number),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_supported_years() {
+        assert_eq!(year_holidays(1872), vec![]);
+        assert_eq!(
+            year_holidays(1873),
+            vec![
+                (
+                    NaiveDate::from_ymd_opt(1873, 11, 3).unwrap(),
+                    "天長節".to_string()
+                ),
+                (
+                    NaiveDate::from_ymd_opt(1873, 11, 23).unwrap(),
+                    "新嘗祭".to_string()
+                ),
+            ]
+        );
+        // 1948年7月20日に祝祭日から国民の祝日に切り替わる
+        let names: Vec<String> = year_holidays(1948)
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "四方拝",
+                "元始祭",
+                "新年宴会",
+                "紀元節",
+                "春季皇霊祭",
+                "神武天皇祭",
+                "天長節",
+                "秋分の日",
+                "文化の日",
+                "勤労感謝の日",
+            ]
+        );
+        assert_eq!(
+            try_year_holidays(1872),
+            Err(YasumiError::UnsupportedYear(1872))
+        );
+        assert_eq!(
+            try_is_holiday("2151-01-01"),
+            Err(YasumiError::UnsupportedYear(2151))
+        );
+        assert!(year_holidays_detail(2150)
+            .iter()
+            .all(|holiday| !holiday.is_extrapolated()));
+        assert!(year_holidays_detail(3000)
+            .iter()
+            .all(|holiday| holiday.is_extrapolated()));
+
+        // 3001年以降の春分の日と秋分の日は近似値で求め, 推定値として扱う
+        let equinox_days = |year| {
+            year_holidays_detail(year)
+                .into_iter()
+                .filter(|holiday| {
+                    matches!(
+                        holiday.id,
+                        HolidayId::VernalEquinoxDay | HolidayId::AutumnalEquinoxDay
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(equinox_days(3000).len(), 2);
+        let equinox_days_3001 = equinox_days(3001);
+        assert_eq!(equinox_days_3001.len(), 2);
+        for holiday in &equinox_days_3001 {
+            assert!(holiday.is_extrapolated());
+            assert_eq!(holiday.status, HolidayStatus::Projected);
+            assert!(is_holiday(holiday.date));
+        }
+        assert_eq!(
+            try_year_holidays(3001),
+            Err(YasumiError::UnsupportedYear(3001))
+        );
+        assert_eq!(
+            try_is_holiday("3001-03-20"),
+            Err(YasumiError::UnsupportedYear(3001))
+        );
+    }
+
+    #[test]
+    fn test_extreme_dates_do_not_panic() {
+        for date in [NaiveDate::MIN, NaiveDate::MAX] {
+            is_holiday(date);
+            is_no_workday(date);
+            holiday_detail(date);
+            between(date, date);
+            next_business_day(date);
+            previous_business_day(date);
+        }
+    }
+
     #[rstest]
     // 1971年
     #[case("1971/01/01", Some("元日".to_string()))]
@@ -308,7 +1186,7 @@ mod tests {
     #[case("1971/03/21", Some("春分の日".to_string()))]
     #[case("1971/04/29", Some("天皇誕生日".to_string()))]
     #[case("1971/05/03", Some("憲法記念日".to_string()))]
-    #[case("1971/05/04", Some("国民の休日".to_string()))]
+    #[case("1971/05/04", None)] // 国民の休日は1985年12月27日から
     #[case("1971/05/05", Some("こどもの日".to_string()))]
     #[case("1971/09/15", Some("敬老の日".to_string()))]
     #[case("1971/09/24", Some("秋分の日".to_string()))]
@@ -899,13 +1777,52 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case("2024/01/01", HolidayId::NewYearsDay, HolidayKind::Statutory, None)]
+    #[case(
+        "2019/05/01",
+        HolidayId::ImperialEventsTheDayOfTheEmperorsEnthronement,
+        HolidayKind::ImperialEvent,
+        None
+    )]
+    #[case(
+        "2019/04/30",
+        HolidayId::CitizensHoliday,
+        HolidayKind::CitizensHoliday,
+        None
+    )]
+    #[case(
+        "2019/05/06",
+        HolidayId::SubstituteHoliday,
+        HolidayKind::Substitute,
+        Some(HolidayId::ChildrensDay)
+    )]
+    #[case(
+        "2009/05/06",
+        HolidayId::SubstituteHoliday,
+        HolidayKind::Substitute,
+        Some(HolidayId::ConstitutionMemorialDay)
+    )]
+    fn test_holiday_detail(
+        #[case] date: &str,
+        #[case] id: HolidayId,
+        #[case] kind: HolidayKind,
+        #[case] substitute_for: Option<HolidayId>,
+    ) {
+        let holiday = holiday_detail(date).unwrap();
+        assert_eq!(holiday.id, id);
+        assert_eq!(holiday.kind, kind);
+        assert_eq!(holiday.substitute_for, substitute_for);
+        assert_eq!(Some(holiday.name), is_holiday_name(date));
+    }
+
     #[rstest]
     // 1971年
     #[case(1971, 1, 2)]
     #[case(1971, 2, 1)]
     #[case(1971, 3, 1)]
     #[case(1971, 4, 1)]
-    #[case(1971, 5, 3)]
+    #[case(1971, 5, 2)]
     #[case(1971, 6, 0)]
     #[case(1971, 7, 0)]
     #[case(1971, 8, 0)]
@@ -1336,7 +2253,7 @@ mod tests {
 
     #[rstest]
     // 1971年
-    #[case(1971, 13)]
+    #[case(1971, 12)]
     // 1988年
     #[case(1988, 14)]
     // 1989年
@@ -0,0 +1,318 @@
+//! 祝日名の言語
+//!
+//! 祝日名を日本語以外で表記するための `Locale` を定義します.
+//! 既定の言語は `set_default_locale` で設定し, プロセス全体で共有されます.
+//! 設定は他のスレッドにも影響するため, 呼び出しごとに言語を変えたい場合は
+//! `Holiday::name_in` を使ってください.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::holiday::{Holiday, HolidayId};
+
+/// 祝日名の表記に使用する言語
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// 日本語 (例: 成人の日)
+    #[default]
+    Ja,
+    /// 英語 (例: Coming of Age Day)
+    En,
+    /// ヘボン式ローマ字 (例: Seijin no Hi)
+    Romaji,
+}
+
+static DEFAULT_LOCALE: AtomicU8 = AtomicU8::new(Locale::Ja as u8);
+
+impl Locale {
+    fn from_u8(value: u8) -> Locale {
+        match value {
+            1 => Locale::En,
+            2 => Locale::Romaji,
+            _ => Locale::Ja,
+        }
+    }
+}
+
+/// 祝日名の既定の言語を設定します.
+/// 設定はプロセス全体に適用されます.
+///
+/// # Arguments
+///
+/// * `locale` - 言語
+pub fn set_default_locale(locale: Locale) {
+    DEFAULT_LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// 祝日名の既定の言語を取得します.
+///
+/// # Returns
+///
+/// `set_default_locale` で設定された言語を返します. 未設定の場合は `Locale::Ja` を返します.
+pub fn default_locale() -> Locale {
+    Locale::from_u8(DEFAULT_LOCALE.load(Ordering::Relaxed))
+}
+
+impl HolidayId {
+    /// 指定した言語での祝日名を取得します.
+    /// `HolidayId::Custom` の場合はキーをそのまま返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - 言語
+    ///
+    /// # Returns
+    ///
+    /// 祝日名を返します.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::{HolidayId, Locale};
+    ///
+    /// assert_eq!(HolidayId::ComingOfAgeDay.name_in(Locale::Ja), "成人の日");
+    /// assert_eq!(HolidayId::ComingOfAgeDay.name_in(Locale::En), "Coming of Age Day");
+    /// assert_eq!(HolidayId::ComingOfAgeDay.name_in(Locale::Romaji), "Seijin no Hi");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        let (ja, en, romaji) = match *self {
+            HolidayId::NewYearsDay => ("元日", "New Year's Day", "Ganjitsu"),
+            HolidayId::ComingOfAgeDay => ("成人の日", "Coming of Age Day", "Seijin no Hi"),
+            HolidayId::NationalFoundationDay => (
+                "建国記念の日",
+                "National Foundation Day",
+                "Kenkoku Kinen no Hi",
+            ),
+            HolidayId::EmperorsBirthday => {
+                ("天皇誕生日", "The Emperor's Birthday", "Tennō Tanjōbi")
+            }
+            HolidayId::VernalEquinoxDay => ("春分の日", "Vernal Equinox Day", "Shunbun no Hi"),
+            HolidayId::GreeneryDay => ("みどりの日", "Greenery Day", "Midori no Hi"),
+            HolidayId::ShowaDay => ("昭和の日", "Showa Day", "Shōwa no Hi"),
+            HolidayId::ConstitutionMemorialDay => {
+                ("憲法記念日", "Constitution Memorial Day", "Kenpō Kinenbi")
+            }
+            HolidayId::ChildrensDay => ("こどもの日", "Children's Day", "Kodomo no Hi"),
+            HolidayId::MarineDay => ("海の日", "Marine Day", "Umi no Hi"),
+            HolidayId::MountainDay => ("山の日", "Mountain Day", "Yama no Hi"),
+            HolidayId::RespectForTheAgedDay => {
+                ("敬老の日", "Respect for the Aged Day", "Keirō no Hi")
+            }
+            HolidayId::AutumnalEquinoxDay => ("秋分の日", "Autumnal Equinox Day", "Shūbun no Hi"),
+            HolidayId::HealthAndSportsDay => ("体育の日", "Health and Sports Day", "Taiiku no Hi"),
+            HolidayId::SportsDay => ("スポーツの日", "Sports Day", "Supōtsu no Hi"),
+            HolidayId::CultureDay => ("文化の日", "Culture Day", "Bunka no Hi"),
+            HolidayId::LaborThanksgivingDay => (
+                "勤労感謝の日",
+                "Labor Thanksgiving Day",
+                "Kinrō Kansha no Hi",
+            ),
+            HolidayId::ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito => (
+                "皇太子・明仁親王の結婚の儀",
+                "The Wedding Ceremony of Crown Prince Akihito",
+                "Kōtaishi Akihito Shinnō no Kekkon no Gi",
+            ),
+            HolidayId::ImperialEventsTheFuneralOfEmperorShowa => (
+                "昭和天皇の大喪の礼",
+                "The Funeral Ceremony of Emperor Showa",
+                "Shōwa Tennō no Taisō no Rei",
+            ),
+            HolidayId::ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor => (
+                "即位の礼正殿の儀",
+                "The Ceremony of the Enthronement of the Emperor",
+                "Sokui no Rei Seiden no Gi",
+            ),
+            HolidayId::ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito => (
+                "皇太子・皇太子徳仁親王の結婚の儀",
+                "The Wedding Ceremony of Crown Prince Naruhito",
+                "Kōtaishi Naruhito Shinnō no Kekkon no Gi",
+            ),
+            HolidayId::ImperialEventsTheDayOfTheEmperorsEnthronement => (
+                "天皇の即位の日",
+                "The Day of the Emperor's Enthronement",
+                "Tennō no Sokui no Hi",
+            ),
+            HolidayId::ImperialEventsTheEnthronementCeremony => (
+                "即位礼正殿の儀",
+                "The Enthronement Ceremony",
+                "Sokuirei Seiden no Gi",
+            ),
+            HolidayId::Shihohai => ("四方拝", "Worship of the Four Directions", "Shihōhai"),
+            HolidayId::GenshiSai => ("元始祭", "Festival of the Origin", "Genshisai"),
+            HolidayId::ShinnenEnkai => ("新年宴会", "New Year's Banquet", "Shinnen Enkai"),
+            HolidayId::KomeiTennoSai => ("孝明天皇祭", "Emperor Kōmei Festival", "Kōmei Tennō Sai"),
+            HolidayId::KigenSetsu => ("紀元節", "Empire Day", "Kigensetsu"),
+            HolidayId::ShunkiKoreiSai => (
+                "春季皇霊祭",
+                "Spring Festival of the Imperial Ancestors",
+                "Shunki Kōreisai",
+            ),
+            HolidayId::JimmuTennoSai => ("神武天皇祭", "Emperor Jimmu Festival", "Jinmu Tennō Sai"),
+            HolidayId::MeijiTennoSai => ("明治天皇祭", "Emperor Meiji Festival", "Meiji Tennō Sai"),
+            HolidayId::TenchoSetsu => ("天長節", "The Emperor's Birthday", "Tenchōsetsu"),
+            HolidayId::TenchoSetsuShukujitsu => (
+                "天長節祝日",
+                "Celebration of the Emperor's Birthday",
+                "Tenchōsetsu Shukujitsu",
+            ),
+            HolidayId::ShukiKoreiSai => (
+                "秋季皇霊祭",
+                "Autumn Festival of the Imperial Ancestors",
+                "Shūki Kōreisai",
+            ),
+            HolidayId::KannameSai => ("神嘗祭", "Kanname Festival", "Kannamesai"),
+            HolidayId::MeijiSetsu => ("明治節", "Meiji Day", "Meijisetsu"),
+            HolidayId::NiinameSai => ("新嘗祭", "Harvest Festival", "Niinamesai"),
+            HolidayId::TaishoTennoSai => {
+                ("大正天皇祭", "Emperor Taishō Festival", "Taishō Tennō Sai")
+            }
+            HolidayId::SubstituteHoliday => ("振替休日", "Substitute Holiday", "Furikae Kyūjitsu"),
+            HolidayId::CitizensHoliday => {
+                ("国民の休日", "Citizens' Holiday", "Kokumin no Kyūjitsu")
+            }
+            HolidayId::Custom(key) => (key, key, key),
+        };
+
+        match locale {
+            Locale::Ja => ja,
+            Locale::En => en,
+            Locale::Romaji => romaji,
+        }
+    }
+}
+
+impl Holiday {
+    /// 指定した言語での祝日名を取得します.
+    /// 振替休日の場合は振替元の祝日名を含みます.
+    /// 独自に定義した祝日と, `HolidayCalendarBuilder::rename` で名前を変更した祝日の場合は
+    /// 言語によらず変更後の名前を返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - 言語
+    ///
+    /// # Returns
+    ///
+    /// 祝日名を返します.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::{holiday_detail, Locale};
+    ///
+    /// let holiday = holiday_detail("2025-11-24").unwrap();
+    /// assert_eq!(holiday.name_in(Locale::Ja), "勤労感謝の日 振替休日");
+    /// assert_eq!(holiday.name_in(Locale::En), "Substitute Holiday for Labor Thanksgiving Day");
+    /// assert_eq!(holiday.name_in(Locale::Romaji), "Kinrō Kansha no Hi Furikae Kyūjitsu");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> String {
+        if let HolidayId::Custom(_) = self.id {
+            return self.name.clone();
+        }
+        // `name` は日本語の祝日名で, 名前を変更した祝日は変更後の名前になっている
+        if locale == Locale::Ja {
+            return self.name.clone();
+        }
+        let Some(original) = self.substitute_for else {
+            if self.name != self.id.name_in(Locale::Ja) {
+                return self.name.clone();
+            }
+            return self.id.name_in(locale).to_string();
+        };
+
+        // 振替元の祝日の名前を変更している場合は変更後の名前を使う
+        let suffix = format!(" {}", HolidayId::SubstituteHoliday.name_in(Locale::Ja));
+        let original = match self.name.strip_suffix(&suffix) {
+            Some(name) if name != original.name_in(Locale::Ja) => name.to_string(),
+            _ => original.name_in(locale).to_string(),
+        };
+        let substitute = HolidayId::SubstituteHoliday.name_in(locale);
+        match locale {
+            Locale::Ja | Locale::Romaji => format!("{} {}", original, substitute),
+            Locale::En => format!("{} for {}", substitute, original),
+        }
+    }
+
+    /// 既定の言語での祝日名を取得します.
+    ///
+    /// # Returns
+    ///
+    /// `default_locale` の言語での祝日名を返します.
+    pub fn localized_name(&self) -> String {
+        self.name_in(default_locale())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::calendar::HolidayCalendar;
+    use crate::public_holiday::HOLIDAYS;
+
+    #[test]
+    fn test_name_in_ja_matches_public_holiday_name() {
+        for holiday in HOLIDAYS.iter() {
+            assert_eq!(holiday.id().name_in(Locale::Ja), holiday.name());
+        }
+    }
+
+    #[test]
+    fn test_name_in_renamed_holiday() {
+        let calendar = HolidayCalendar::builder()
+            .rename(HolidayId::SportsDay, "体育の日")
+            .rename(HolidayId::ChildrensDay, "子供の日")
+            .build();
+
+        let holiday = calendar.holiday_detail("2024-10-14").unwrap();
+        assert_eq!(holiday.name, "体育の日");
+        assert_eq!(holiday.name_in(Locale::Ja), "体育の日");
+        assert_eq!(holiday.name_in(Locale::En), "体育の日");
+        assert_eq!(
+            calendar.holiday_name_in("2024-10-14", Locale::Ja),
+            Some("体育の日".to_string())
+        );
+
+        // 振替休日は変更後の名前から組み立てる
+        let holiday = calendar.holiday_detail("2024-05-06").unwrap();
+        assert_eq!(holiday.name_in(Locale::Ja), "子供の日 振替休日");
+        assert_eq!(
+            holiday.name_in(Locale::En),
+            "Substitute Holiday for 子供の日"
+        );
+        assert_eq!(holiday.name_in(Locale::Romaji), "子供の日 Furikae Kyūjitsu");
+
+        // 名前を変更していない祝日は翻訳する
+        assert_eq!(
+            calendar.holiday_name_in("2024-11-04", Locale::En),
+            Some("Substitute Holiday for Culture Day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_locale_from_u8() {
+        assert_eq!(Locale::from_u8(Locale::Ja as u8), Locale::Ja);
+        assert_eq!(Locale::from_u8(Locale::En as u8), Locale::En);
+        assert_eq!(Locale::from_u8(Locale::Romaji as u8), Locale::Romaji);
+    }
+
+    #[test]
+    fn test_default_locale() {
+        // 既定の言語はプロセス全体の設定のため, 最後に元の設定に戻す
+        let previous = default_locale();
+        let holiday = HolidayCalendar::national()
+            .holiday_detail("2024-01-08")
+            .unwrap();
+
+        set_default_locale(Locale::En);
+        assert_eq!(default_locale(), Locale::En);
+        assert_eq!(holiday.localized_name(), "Coming of Age Day");
+
+        set_default_locale(Locale::Romaji);
+        assert_eq!(default_locale(), Locale::Romaji);
+        assert_eq!(holiday.localized_name(), "Seijin no Hi");
+
+        set_default_locale(Locale::Ja);
+        assert_eq!(holiday.localized_name(), "成人の日");
+
+        set_default_locale(previous);
+        assert_eq!(default_locale(), previous);
+    }
+}
@@ -0,0 +1,273 @@
+//! 日本取引所グループ(JPX)の休業日
+//!
+//! 東京証券取引所などの JPX の市場は, 土日祝に加えて年末年始(12月31日, 1月2日, 1月3日)に休業します.
+//!
+//! 現行の休業日の規則をすべての年に適用します. 土曜日の立会があった1989年1月以前など,
+//! 規則が異なる過去の年の取引日は正しくありません.
+//!
+//! 2020年10月1日は株式売買システムの障害で終日売買が停止されました. 休業日ではないため [`HolidayCalendar::jpx`]
+//! には含めず, 取引日のカレンダー [`HolidayCalendar::jpx_trading`] と取引日の判定(`is_trading_day` など)で
+//! 取引日から除きます.
+
+use std::sync::OnceLock;
+
+use chrono::NaiveDate;
+
+use crate::calendar::HolidayCalendar;
+use crate::datelike::DateLike;
+
+impl HolidayCalendar {
+    /// 日本取引所グループ(JPX)の休業日のカレンダーを作成します.
+    ///
+    /// 国民の祝日に加えて, 年末年始の休業日を含みます.
+    /// 終日売買停止となった日は休業日ではないため含みません.
+    /// 現行の規則を過去の年にも適用します.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let jpx = HolidayCalendar::jpx();
+    /// assert_eq!(jpx.holiday_name("2024-12-31"), Some("年末年始休業日".to_string()));
+    /// assert_eq!(jpx.is_business_day("2024-12-30"), true);
+    /// ```
+    pub fn jpx() -> HolidayCalendar {
+        HolidayCalendar::builder()
+            .fixed_dates(
+                "jpx_year_end_holiday",
+                "年末年始休業日",
+                &[(12, 31), (1, 2), (1, 3)],
+            )
+            .build()
+    }
+
+    /// JPX の取引日のカレンダーを作成します.
+    ///
+    /// [`HolidayCalendar::jpx`] の休業日に, 終日売買停止となった日を「終日売買停止」として加えます.
+    /// 営業日の計算は `is_trading_day` などの取引日の関数と一致します.
+    ///
+    /// # Examples
+    /// ```
+    /// use yasumi::HolidayCalendar;
+    ///
+    /// let trading = HolidayCalendar::jpx_trading();
+    /// assert_eq!(trading.holiday_name("2020-10-01"), Some("終日売買停止".to_string()));
+    /// assert_eq!(trading.is_business_day("2020-10-01"), false);
+    /// assert_eq!(HolidayCalendar::jpx().is_business_day("2020-10-01"), true);
+    /// ```
+    pub fn jpx_trading() -> HolidayCalendar {
+        TRADING_HALTS
+            .iter()
+            .fold(HolidayCalendar::jpx().to_builder(), |builder, (y, m, d)| {
+                builder.date(
+                    "jpx_trading_halt",
+                    "終日売買停止",
+                    NaiveDate::from_ymd_opt(*y, *m, *d).unwrap(),
+                )
+            })
+            .build()
+    }
+}
+
+/// 休業日ではないが, 終日売買が停止された日
+///
+/// 2020年10月1日: 株式売買システムの障害
+const TRADING_HALTS: [(i32, u32, u32); 1] = [(2020, 10, 1)];
+
+/// JPX の取引日のカレンダーを取得します.
+fn jpx_calendar() -> &'static HolidayCalendar {
+    static JPX_CALENDAR: OnceLock<HolidayCalendar> = OnceLock::new();
+    JPX_CALENDAR.get_or_init(HolidayCalendar::jpx_trading)
+}
+
+/// 指定した日付が JPX の取引日かどうかを判定します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 取引日の場合は `true` を返します. それ以外の場合は `false` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::is_trading_day;
+///
+/// assert_eq!(is_trading_day("2024-01-03"), false);
+/// assert_eq!(is_trading_day("2024-01-04"), true);
+/// ```
+pub fn is_trading_day<T: DateLike>(date: T) -> bool {
+    jpx_calendar().is_business_day(date)
+}
+
+/// 指定した日付の翌取引日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 翌取引日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::next_trading_day;
+///
+/// let date = next_trading_day("2024-12-30");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2025, 1, 6));
+/// ```
+pub fn next_trading_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    jpx_calendar().next_business_day(date)
+}
+
+/// 指定した日付の前取引日を取得します.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+///
+/// # Returns
+///
+/// 前取引日を返します. 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::previous_trading_day;
+///
+/// let date = previous_trading_day("2025-01-06");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 12, 30));
+/// ```
+pub fn previous_trading_day<T: DateLike>(date: T) -> Option<NaiveDate> {
+    jpx_calendar().previous_business_day(date)
+}
+
+/// 指定した日付から指定した取引日数後の日付を取得します.
+/// 指定した日付は数えません.
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `days` - 取引日数
+///
+/// # Returns
+///
+/// 取引日数後の日付を返します. 日付が不正な場合は `None` を返します.
+pub fn add_trading_days<T: DateLike>(date: T, days: u32) -> Option<NaiveDate> {
+    jpx_calendar().add_business_days(date, days)
+}
+
+/// 指定した期間の取引日数を取得します.
+/// 開始日は含まず, 終了日は含みます.
+///
+/// # Arguments
+///
+/// * `start_date` - 開始日(含まない)
+/// * `end_date` - 終了日(含む)
+///
+/// # Returns
+///
+/// 取引日数を返します. 開始日が終了日より後の場合は負の値を返します.
+/// 日付が不正な場合は `None` を返します.
+///
+/// # Examples
+/// ```
+/// use yasumi::trading_days_between;
+///
+/// let days = trading_days_between("2024-12-27", "2025-01-06");
+/// assert_eq!(days, Some(2));
+/// ```
+pub fn trading_days_between<T: DateLike>(start_date: T, end_date: T) -> Option<i64> {
+    jpx_calendar().business_days_between(start_date, end_date)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2024/12/30", true)] // 大納会
+    #[case("2024/12/31", false)]
+    #[case("2025/01/01", false)] // 元日
+    #[case("2025/01/02", false)]
+    #[case("2025/01/03", false)]
+    #[case("2025/01/06", true)] // 大発会
+    #[case("2020/10/01", false)] // 終日売買停止
+    #[case("2020/10/02", true)]
+    #[case("2024/09/16", false)] // 敬老の日
+    fn test_is_trading_day(#[case] date: &str, #[case] expected: bool) {
+        assert_eq!(is_trading_day(date), expected);
+    }
+
+    #[test]
+    fn test_trading_halt_is_not_a_closure() {
+        let jpx = HolidayCalendar::jpx();
+        assert_eq!(jpx.holiday_name("2020/10/01"), None);
+        assert!(jpx.is_business_day("2020/10/01"));
+        assert!(!is_trading_day("2020/10/01"));
+        assert_eq!(
+            next_trading_day("2020/09/30"),
+            NaiveDate::from_ymd_opt(2020, 10, 2)
+        );
+    }
+
+    #[test]
+    fn test_trading_calendar_matches_trading_days() {
+        let trading = HolidayCalendar::jpx_trading();
+        let start = NaiveDate::from_ymd_opt(2020, 9, 1).unwrap();
+        for date in start.iter_days().take(120) {
+            assert_eq!(
+                trading.is_business_day(date),
+                is_trading_day(date),
+                "{date}"
+            );
+            assert_eq!(
+                trading.next_business_day(date),
+                next_trading_day(date),
+                "{date}"
+            );
+        }
+        assert_eq!(
+            trading.business_days_between("2020-09-30", "2020-10-02"),
+            trading_days_between("2020-09-30", "2020-10-02")
+        );
+        assert_eq!(trading_days_between("2020-09-30", "2020-10-02"), Some(1));
+    }
+
+    #[test]
+    fn test_closures_are_national_and_year_end_holidays() {
+        let jpx = HolidayCalendar::jpx();
+        let national = HolidayCalendar::national();
+        for year in 1989..=2030 {
+            let mut expected: Vec<_> = national
+                .year_holidays(year)
+                .into_iter()
+                .map(|(date, _)| date)
+                .chain(
+                    [(1, 2), (1, 3), (12, 31)]
+                        .map(|(m, d)| NaiveDate::from_ymd_opt(year, m, d).unwrap()),
+                )
+                .collect();
+            expected.sort();
+            expected.dedup();
+            let closures: Vec<_> = jpx
+                .year_holidays(year)
+                .into_iter()
+                .map(|(date, _)| date)
+                .collect();
+            assert_eq!(closures, expected, "{year}");
+        }
+    }
+
+    #[test]
+    fn test_year_end_holiday_is_not_substituted() {
+        // 2023年12月31日(日)の翌日は元日であり, 1月2日以降も振替休日にはならない
+        let jpx = HolidayCalendar::jpx();
+        assert_eq!(jpx.holiday_name("2024/01/04"), None);
+    }
+}
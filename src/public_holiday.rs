@@ -1,6 +1,86 @@
-use chrono::{Datelike, NaiveDate};
+//! 国民の祝日の判定規則
+//!
+//! 個々の祝日は [`PublicHoliday`] を実装した構造体として定義されています.
+//! 各規則は根拠となる法令の施行日から適用され, 年の途中の改正も日単位で反映されます.
+//! 1948年7月19日以前は, 太政官布告および勅令で定められた祝祭日を祝日として扱います.
+//!
+//! 特定の祝日の規則だけを評価することもできます.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use yasumi::public_holiday::{MarineDay, PublicHoliday};
+//!
+//! let date = NaiveDate::from_ymd_opt(2021, 7, 22).unwrap();
+//! assert!(MarineDay.is_holiday(&date));
+//! assert_eq!(MarineDay.name(), "海の日");
+//! ```
 
-pub const HOLIDAYS: [&dyn PublicHoliday; 23] = [
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::equinox::{autumnal_equinox_status, vernal_equinox_status};
+use crate::holiday::{HolidayId, HolidayKind, HolidayStatus};
+use crate::law::{
+    is_holiday_in, period_as_known_on, period_on, possible_dates_as_known_on, ymd, DateRule,
+    Period, HEISEI_10_LAW_141, HEISEI_13_LAW_59, HEISEI_17_LAW_43, HEISEI_1_LAW_4, HEISEI_1_LAW_5,
+    HEISEI_26_LAW_43, HEISEI_29_LAW_63, HEISEI_2_LAW_24, HEISEI_30_LAW_55, HEISEI_30_LAW_57,
+    HEISEI_30_LAW_99, HEISEI_5_LAW_32, HEISEI_7_LAW_22, MEIJI_11_DECREE_23, MEIJI_12_DECREE_27,
+    MEIJI_6_DECREE_344, REIWA_2_LAW_68, SHOWA_23_LAW_178, SHOWA_2_ORDINANCE_25, SHOWA_34_LAW_16,
+    SHOWA_41_CABINET_ORDER_376, SHOWA_41_LAW_86, SHOWA_48_LAW_10, SHOWA_60_LAW_103,
+    TAISHO_1_ORDINANCE_19, TAISHO_2_ORDINANCE_259,
+};
+
+/// 祝日を判定できる最初の年
+///
+/// 年中祭日祝日ノ休暇日ヲ定ム(明治6年太政官布告第344号)が布告された年です.
+const FIRST_SUPPORTED_YEAR: i32 = 1873;
+
+/// 祝日を判定できる最後の年
+///
+/// 春分日・秋分日を従来の近似式と照合している最後の年です.
+const LAST_SUPPORTED_YEAR: i32 = 2150;
+
+/// 祝日を判定できる年の範囲を返します.
+///
+/// 範囲外の年は `try_` で始まる関数では `YasumiError::UnsupportedYear` になります.
+/// それ以外の関数では範囲外の年の祝日は現行の規則を延長した推定値となり,
+/// `Holiday::is_extrapolated` が `true` を返します.
+///
+/// 3001年から4000年までの春分の日と秋分の日は近似値で求め, 同じく `is_extrapolated` が `true` になります.
+/// 4001年以降は春分日・秋分日を求められないため, 春分の日と秋分の日, およびそれらによる振替休日と
+/// 国民の休日を返しません.
+///
+/// # Examples
+/// ```
+/// use yasumi::supported_years;
+///
+/// assert_eq!(supported_years(), 1873..=2150);
+/// assert!(supported_years().contains(&2024));
+/// ```
+pub fn supported_years() -> RangeInclusive<i32> {
+    FIRST_SUPPORTED_YEAR..=LAST_SUPPORTED_YEAR
+}
+
+/// 指定した年の祝日を判定できるかどうかを返します.
+///
+/// # Arguments
+///
+/// * `year` - 年
+///
+/// # Examples
+/// ```
+/// use yasumi::is_supported_year;
+///
+/// assert!(is_supported_year(2024));
+/// assert!(!is_supported_year(3000));
+/// ```
+pub fn is_supported_year(year: i32) -> bool {
+    supported_years().contains(&year)
+}
+
+/// 国民の祝日に関する法律, 皇室の行事に伴う休日および1948年以前の祝祭日の一覧
+pub const HOLIDAYS: [&dyn PublicHoliday; 38] = [
     &NewYearsDay,
     &ComingOfAgeDay,
     &NationalFoundationDay,
@@ -24,431 +104,1365 @@ pub const HOLIDAYS: [&dyn PublicHoliday; 23] = [
     &ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito,
     &ImperialEventsTheDayOfTheEmperorsEnthronement,
     &ImperialEventsTheEnthronementCeremony,
+    &Shihohai,
+    &GenshiSai,
+    &ShinnenEnkai,
+    &KomeiTennoSai,
+    &KigenSetsu,
+    &ShunkiKoreiSai,
+    &JimmuTennoSai,
+    &MeijiTennoSai,
+    &TenchoSetsu,
+    &TenchoSetsuShukujitsu,
+    &ShukiKoreiSai,
+    &KannameSai,
+    &MeijiSetsu,
+    &NiinameSai,
+    &TaishoTennoSai,
 ];
 
 /// 祝日を表すトレイト
-pub trait PublicHoliday {
+///
+/// # Examples
+/// ```
+/// use chrono::{Datelike, NaiveDate};
+/// use yasumi::public_holiday::PublicHoliday;
+/// use yasumi::{HolidayId, HolidayKind};
+///
+/// /// 創立記念日
+/// struct FoundationDay;
+/// impl PublicHoliday for FoundationDay {
+///     fn is_holiday(&self, date: &NaiveDate) -> bool {
+///         date.month() == 6 && date.day() == 1
+///     }
+///     fn name(&self) -> String {
+///         "創立記念日".to_string()
+///     }
+///     fn id(&self) -> HolidayId {
+///         HolidayId::Custom("foundation_day")
+///     }
+///     fn kind(&self) -> HolidayKind {
+///         HolidayKind::Custom
+///     }
+/// }
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+/// assert!(FoundationDay.is_holiday(&date));
+/// ```
+pub trait PublicHoliday: Send + Sync {
+    /// 指定した日付がこの祝日かどうかを判定します.
     fn is_holiday(&self, date: &NaiveDate) -> bool;
+    /// 祝日名を返します.
     fn name(&self) -> String;
+    /// 祝日の識別子を返します.
+    fn id(&self) -> HolidayId;
+    /// 祝日の種類を返します.
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::Statutory
+    }
+    /// 指定した日付の確からしさを返します.
+    ///
+    /// 既定では法律で日付が確定している祝日として扱います.
+    fn status(&self, _date: &NaiveDate) -> HolidayStatus {
+        HolidayStatus::Statutory
+    }
+    /// 指定した時点で分かっていた, 指定した日付の確からしさを返します.
+    ///
+    /// 既定では `status` と同じです. 日付が後から公表される祝日は, 公表前の時点では `Projected` を返します.
+    fn status_as_known_on(&self, date: &NaiveDate, _known_on: &NaiveDate) -> HolidayStatus {
+        self.status(date)
+    }
+    /// 根拠となる法令ごとの規則の期間を返します.
+    ///
+    /// 既定では期間を持たない祝日として扱います.
+    fn periods(&self) -> &'static [Period] {
+        &[]
+    }
+    /// 指定した日付がこの祝日である根拠となった期間を返します.
+    ///
+    /// 指定した日付がこの祝日でない場合は `None` を返します.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use yasumi::public_holiday::{EmperorsBirthday, PublicHoliday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2018, 12, 23).unwrap();
+    /// let period = EmperorsBirthday.legal_basis(&date).unwrap();
+    /// assert_eq!(period.law.number, "平成元年法律第5号");
+    /// ```
+    fn legal_basis(&self, date: &NaiveDate) -> Option<&'static Period> {
+        if !self.is_holiday(date) {
+            return None;
+        }
+        period_on(self.periods(), date)
+    }
+    /// この祝日になりうる日付の範囲を返します.
+    ///
+    /// `holiday_iter` や `next_holiday` はこの範囲の外を探しません. 既定では法令の期間から求め,
+    /// 期間を持たない祝日はすべての日付とします. 祝日になりえない場合は `None` を返します.
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        if self.periods().is_empty() {
+            return Some(NaiveDate::MIN..=NaiveDate::MAX);
+        }
+        possible_dates_as_known_on(self.periods(), &NaiveDate::MAX)
+    }
+}
+
+impl<H: PublicHoliday + ?Sized> PublicHoliday for &H {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+    fn name(&self) -> String {
+        (**self).name()
+    }
+    fn id(&self) -> HolidayId {
+        (**self).id()
+    }
+    fn kind(&self) -> HolidayKind {
+        (**self).kind()
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        (**self).status(date)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        (**self).status_as_known_on(date, known_on)
+    }
+    fn periods(&self) -> &'static [Period] {
+        (**self).periods()
+    }
+    fn legal_basis(&self, date: &NaiveDate) -> Option<&'static Period> {
+        (**self).legal_basis(date)
+    }
+    fn possible_dates(&self) -> Option<RangeInclusive<NaiveDate>> {
+        (**self).possible_dates()
+    }
 }
 
 /// 元日
 pub struct NewYearsDay;
+impl NewYearsDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::Fixed { month: 1, day: 1 },
+    )];
+}
 impl PublicHoliday for NewYearsDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.month() == 1 && date.day() == 1
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "元日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::NewYearsDay
+    }
 }
 
 /// 成人の日
 pub struct ComingOfAgeDay;
+impl ComingOfAgeDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_23_LAW_178,
+            ymd(1948, 7, 20),
+            DateRule::Fixed { month: 1, day: 15 },
+        ),
+        Period::since(
+            &HEISEI_10_LAW_141,
+            ymd(2000, 1, 1),
+            DateRule::NthWeekday {
+                month: 1,
+                week: 2,
+                weekday: Weekday::Mon,
+            },
+        ),
+    ];
+}
 impl PublicHoliday for ComingOfAgeDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        if date.year() <= 1999 && date.month() == 1 && date.day() == 15 {
-            return true;
-        }
-        if date.year() >= 2000
-            && date.month() == 1
-            && date.day() == week_day(date, 2, 1).unwrap().day()
-        {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "成人の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ComingOfAgeDay
+    }
 }
 
 /// 建国記念の日
 pub struct NationalFoundationDay;
+impl NationalFoundationDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_41_CABINET_ORDER_376,
+        ymd(1966, 12, 9),
+        DateRule::Fixed { month: 2, day: 11 },
+    )];
+}
 impl PublicHoliday for NationalFoundationDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.year() >= 1967 && date.month() == 2 && date.day() == 11
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "建国記念の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::NationalFoundationDay
+    }
 }
 
 /// 天皇誕生日
 pub struct EmperorsBirthday;
+impl EmperorsBirthday {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_23_LAW_178,
+            ymd(1948, 7, 20),
+            DateRule::Fixed { month: 4, day: 29 },
+        ),
+        Period::since(
+            &HEISEI_1_LAW_5,
+            ymd(1989, 2, 17),
+            DateRule::Fixed { month: 12, day: 23 },
+        ),
+        Period::since(
+            &HEISEI_29_LAW_63,
+            ymd(2019, 4, 30),
+            DateRule::Fixed { month: 2, day: 23 },
+        ),
+    ];
+}
 impl PublicHoliday for EmperorsBirthday {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 1948年から1988年まで: 4月29日
-        if date.year() >= 1948 && date.year() <= 1988 && date.month() == 4 && date.day() == 29 {
-            return true;
-        }
-        // 1989年から2018年まで: 12月23日
-        // 2019: 国民の祝日に関する法律(昭和23年法律第178号)の一部改正
-        if date.year() >= 1989 && date.year() <= 2018 && date.month() == 12 && date.day() == 23 {
-            return true;
-        }
-        // 2019: 国民の祝日に関する法律(昭和23年法律第178号)の一部改正
-        if date.year() >= 2020 && date.month() == 2 && date.day() == 23 {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "天皇誕生日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::EmperorsBirthday
+    }
 }
 
 /// 春分の日
+///
+/// 春分日は1000年から3000年まで計算できます. 3001年から4000年までは近似値で, 4001年以降は祝日になりません.
 pub struct VernalEquinoxDay;
+impl VernalEquinoxDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::VernalEquinox,
+    )];
+}
 impl PublicHoliday for VernalEquinoxDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 春分の日の日付を計算する
-        // 春季皇霊祭: 1879-1947
-        // 春分の日: 1948-
-        fn vernal_equinox_day(year: i32) -> u32 {
-            // NOTE: http://mt-soft.sakura.ne.jp/kyozai/excel_high/200_jissen_kiso/60_syunbun.htm
-
-            if year <= 1948 {
-                return 0;
-            }
-
-            let i;
-            if (1851..=1899).contains(&year) {
-                i = 19.8277;
-            } else if (1900..=1979).contains(&year) {
-                i = 20.8357;
-            } else if (1980..=2099).contains(&year) {
-                i = 20.8431;
-            } else if (2100..=2150).contains(&year) {
-                i = 21.8510;
-            } else {
-                i = 0.0;
-            }
-
-            (i + 0.242194 * (year as f64 - 1980.0) - ((year as f64 - 1980.0) / 4.0).floor()).floor()
-                as u32
-        }
-
-        if date.month() == 3 && date.day() == vernal_equinox_day(date.year()) {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "春分の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::VernalEquinoxDay
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        vernal_equinox_status(date.year(), &NaiveDate::MAX)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        vernal_equinox_status(date.year(), known_on)
+    }
 }
 
 /// みどりの日
 pub struct GreeneryDay;
+impl GreeneryDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &HEISEI_1_LAW_5,
+            ymd(1989, 2, 17),
+            DateRule::Fixed { month: 4, day: 29 },
+        ),
+        Period::since(
+            &HEISEI_17_LAW_43,
+            ymd(2007, 1, 1),
+            DateRule::Fixed { month: 5, day: 4 },
+        ),
+    ];
+}
 impl PublicHoliday for GreeneryDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        if date.year() >= 1989 && date.year() <= 2006 && date.month() == 4 && date.day() == 29 {
-            return true;
-        }
-        if date.year() >= 2007 && date.month() == 5 && date.day() == 4 {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "みどりの日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::GreeneryDay
+    }
 }
 
 /// 昭和の日
 pub struct ShowaDay;
+impl ShowaDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &HEISEI_17_LAW_43,
+        ymd(2007, 1, 1),
+        DateRule::Fixed { month: 4, day: 29 },
+    )];
+}
 impl PublicHoliday for ShowaDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        if date.year() >= 2007 && date.month() == 4 && date.day() == 29 {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "昭和の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ShowaDay
+    }
 }
 
 /// 憲法記念日
 pub struct ConstitutionMemorialDay;
+impl ConstitutionMemorialDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::Fixed { month: 5, day: 3 },
+    )];
+}
 impl PublicHoliday for ConstitutionMemorialDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.month() == 5 && date.day() == 3
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "憲法記念日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ConstitutionMemorialDay
+    }
 }
 
 /// こどもの日
 pub struct ChildrensDay;
+impl ChildrensDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::Fixed { month: 5, day: 5 },
+    )];
+}
 impl PublicHoliday for ChildrensDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.month() == 5 && date.day() == 5
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "こどもの日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ChildrensDay
+    }
 }
 
 /// 海の日
 pub struct MarineDay;
+impl MarineDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &HEISEI_7_LAW_22,
+            ymd(1996, 1, 1),
+            DateRule::Fixed { month: 7, day: 20 },
+        ),
+        Period::since(
+            &HEISEI_13_LAW_59,
+            ymd(2003, 1, 1),
+            DateRule::NthWeekday {
+                month: 7,
+                week: 3,
+                weekday: Weekday::Mon,
+            },
+        ),
+        Period::only(
+            &HEISEI_30_LAW_55,
+            ymd(2020, 1, 1),
+            ymd(2020, 12, 31),
+            DateRule::Date(ymd(2020, 7, 23)),
+        ),
+        Period::only(
+            &REIWA_2_LAW_68,
+            ymd(2021, 1, 1),
+            ymd(2021, 12, 31),
+            DateRule::Date(ymd(2021, 7, 22)),
+        ),
+    ];
+}
 impl PublicHoliday for MarineDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 2020: 国民の祝日に関する法律(昭和23年法律第178号)の特例
-        if date.year() == 2020 {
-            return *date == NaiveDate::from_ymd_opt(2020, 7, 23).unwrap();
-        }
-        // 2021: 五輪特別措置法改正案
-        if date.year() == 2021 {
-            return *date == NaiveDate::from_ymd_opt(2021, 7, 22).unwrap();
-        }
-        if date.year() >= 1996 && date.year() <= 2002 && date.month() == 7 && date.day() == 20 {
-            return true;
-        }
-        // 2020: 国民の祝日に関する法律の一部を改正する法律(平成30年法律第57号)
-        if date.year() >= 2003
-            && date.month() == 7
-            && date.day() == week_day(date, 3, 1).unwrap().day()
-        {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "海の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::MarineDay
+    }
 }
 
 /// 山の日
 pub struct MountainDay;
+impl MountainDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &HEISEI_26_LAW_43,
+            ymd(2016, 1, 1),
+            DateRule::Fixed { month: 8, day: 11 },
+        ),
+        Period::only(
+            &HEISEI_30_LAW_55,
+            ymd(2020, 1, 1),
+            ymd(2020, 12, 31),
+            DateRule::Date(ymd(2020, 8, 10)),
+        ),
+        Period::only(
+            &REIWA_2_LAW_68,
+            ymd(2021, 1, 1),
+            ymd(2021, 12, 31),
+            DateRule::Date(ymd(2021, 8, 8)),
+        ),
+    ];
+}
 impl PublicHoliday for MountainDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 2020: 国民の祝日に関する法律(昭和23年法律第178号)の特例
-        if date.year() == 2020 {
-            return *date == NaiveDate::from_ymd_opt(2020, 8, 10).unwrap();
-        }
-        // 2021: 五輪特別措置法改正案
-        if date.year() == 2021 {
-            return *date == NaiveDate::from_ymd_opt(2021, 8, 8).unwrap();
-        }
-        // 2016: 国民の祝日に関する法律の一部を改正する法律(平成26年法律第43号)
-        // 2020: 国民の祝日に関する法律の一部を改正する法律(平成30年法律第57号)
-        if date.year() >= 2016 && date.month() == 8 && date.day() == 11 {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "山の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::MountainDay
+    }
 }
 
 /// 敬老の日
 pub struct RespectForTheAgedDay;
+impl RespectForTheAgedDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_41_LAW_86,
+            ymd(1966, 6, 25),
+            DateRule::Fixed { month: 9, day: 15 },
+        ),
+        Period::since(
+            &HEISEI_13_LAW_59,
+            ymd(2003, 1, 1),
+            DateRule::NthWeekday {
+                month: 9,
+                week: 3,
+                weekday: Weekday::Mon,
+            },
+        ),
+    ];
+}
 impl PublicHoliday for RespectForTheAgedDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        if date.year() >= 1966 && date.year() <= 2002 && date.month() == 9 && date.day() == 15 {
-            return true;
-        }
-        if date.year() >= 2003
-            && date.month() == 9
-            && date.day() == week_day(date, 3, 1).unwrap().day()
-        {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "敬老の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::RespectForTheAgedDay
+    }
 }
 
 /// 秋分の日
+///
+/// 秋分日は1000年から3000年まで計算できます. 3001年から4000年までは近似値で, 4001年以降は祝日になりません.
 pub struct AutumnalEquinoxDay;
+impl AutumnalEquinoxDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::AutumnalEquinox,
+    )];
+}
 impl PublicHoliday for AutumnalEquinoxDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 秋分の日の日付を計算する
-        // 秋季皇霊祭: 1879-1947
-        // 秋分の日: 1948-
-        fn autumnal_equinox_day(year: i32) -> u32 {
-            // NOTE: http://mt-soft.sakura.ne.jp/kyozai/excel_high/200_jissen_kiso/60_syunbun.htm
-
-            if year <= 1948 {
-                return 0;
-            }
-
-            let i;
-            if (1851..=1899).contains(&year) {
-                i = 22.2588;
-            } else if (1900..=1979).contains(&year) {
-                i = 23.2588;
-            } else if (1980..=2099).contains(&year) {
-                i = 23.2488;
-            } else if (2100..=2150).contains(&year) {
-                i = 24.2488;
-            } else {
-                i = 0.0;
-            }
-
-            (i + 0.242194 * (year as f64 - 1980.0) - ((year as f64 - 1980.0) / 4.0).floor()).floor()
-                as u32
-        }
-
-        if date.month() == 9 && date.day() == autumnal_equinox_day(date.year()) {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "秋分の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::AutumnalEquinoxDay
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        autumnal_equinox_status(date.year(), &NaiveDate::MAX)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        autumnal_equinox_status(date.year(), known_on)
+    }
 }
 
 /// 体育の日
 pub struct HealthAndSportsDay;
+impl HealthAndSportsDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_41_LAW_86,
+            ymd(1966, 6, 25),
+            DateRule::Fixed { month: 10, day: 10 },
+        ),
+        Period::since(
+            &HEISEI_10_LAW_141,
+            ymd(2000, 1, 1),
+            DateRule::NthWeekday {
+                month: 10,
+                week: 2,
+                weekday: Weekday::Mon,
+            },
+        ),
+        Period::abolished(&HEISEI_30_LAW_57, ymd(2020, 1, 1)),
+    ];
+}
 impl PublicHoliday for HealthAndSportsDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        if date.year() >= 1966 && date.year() <= 1999 && date.month() == 10 && date.day() == 10 {
-            return true;
-        }
-        if date.year() >= 2000
-            && date.year() <= 2019
-            && date.month() == 10
-            && date.day() == week_day(date, 2, 1).unwrap().day()
-        {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "体育の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::HealthAndSportsDay
+    }
 }
 
 /// スポーツの日
 pub struct SportsDay;
+impl SportsDay {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &HEISEI_30_LAW_57,
+            ymd(2020, 1, 1),
+            DateRule::NthWeekday {
+                month: 10,
+                week: 2,
+                weekday: Weekday::Mon,
+            },
+        ),
+        Period::only(
+            &HEISEI_30_LAW_55,
+            ymd(2020, 1, 1),
+            ymd(2020, 12, 31),
+            DateRule::Date(ymd(2020, 7, 24)),
+        ),
+        Period::only(
+            &REIWA_2_LAW_68,
+            ymd(2021, 1, 1),
+            ymd(2021, 12, 31),
+            DateRule::Date(ymd(2021, 7, 23)),
+        ),
+    ];
+}
 impl PublicHoliday for SportsDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        // 2020: 国民の祝日に関する法律(昭和23年法律第178号)の特例
-        if date.year() == 2020 {
-            return *date == NaiveDate::from_ymd_opt(2020, 7, 24).unwrap();
-        }
-        // 2021: 五輪特別措置法改正案
-        if date.year() == 2021 {
-            return *date == NaiveDate::from_ymd_opt(2021, 7, 23).unwrap();
-        }
-        // 2020: 国民の祝日に関する法律の一部を改正する法律(平成30年法律第57号)
-        //       国民の祝日に関する法律(昭和23年法律第178号)の特例
-        if date.year() >= 2020
-            && date.month() == 10
-            && date.day() == week_day(date, 2, 1).unwrap().day()
-        {
-            return true;
-        }
-        false
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "スポーツの日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::SportsDay
+    }
 }
 
 /// 文化の日
 pub struct CultureDay;
+impl CultureDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::Fixed { month: 11, day: 3 },
+    )];
+}
 impl PublicHoliday for CultureDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.month() == 11 && date.day() == 3
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "文化の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::CultureDay
+    }
 }
 
 /// 勤労感謝の日
 pub struct LaborThanksgivingDay;
+impl LaborThanksgivingDay {
+    const PERIODS: &'static [Period] = &[Period::since(
+        &SHOWA_23_LAW_178,
+        ymd(1948, 7, 20),
+        DateRule::Fixed { month: 11, day: 23 },
+    )];
+}
 impl PublicHoliday for LaborThanksgivingDay {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        date.month() == 11 && date.day() == 23
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
     fn name(&self) -> String {
         "勤労感謝の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::LaborThanksgivingDay
+    }
 }
 
 /// 皇太子・明仁親王の結婚の儀
 pub struct ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito;
+impl ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito {
+    const PERIODS: &'static [Period] = &[Period::once(&SHOWA_34_LAW_16, ymd(1959, 4, 10))];
+}
 impl PublicHoliday for ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(1959, 4, 10).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "皇太子・明仁親王の結婚の儀".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheWeddingCeremonyOfCrownPrinceAkihito
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
 }
 
 /// 昭和天皇の大喪の礼
 pub struct ImperialEventsTheFuneralOfEmperorShowa;
+impl ImperialEventsTheFuneralOfEmperorShowa {
+    const PERIODS: &'static [Period] = &[Period::once(&HEISEI_1_LAW_4, ymd(1989, 2, 24))];
+}
 impl PublicHoliday for ImperialEventsTheFuneralOfEmperorShowa {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(1989, 2, 24).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "昭和天皇の大喪の礼".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheFuneralOfEmperorShowa
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
 }
 
 /// 即位の礼正殿の儀
 pub struct ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor;
+impl ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor {
+    const PERIODS: &'static [Period] = &[Period::once(&HEISEI_2_LAW_24, ymd(1990, 11, 12))];
+}
 impl PublicHoliday for ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(1990, 11, 12).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "即位の礼正殿の儀".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheCeremonyOfTheEnthronementOfTheEmperor
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
 }
 
 /// 皇太子・皇太子徳仁親王の結婚の儀
 pub struct ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito;
+impl ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito {
+    const PERIODS: &'static [Period] = &[Period::once(&HEISEI_5_LAW_32, ymd(1993, 6, 9))];
+}
 impl PublicHoliday for ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(1993, 6, 9).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "皇太子・皇太子徳仁親王の結婚の儀".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheWeddingCeremonyOfCrownPrinceNaruhito
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
 }
 
 /// 天皇の即位の日
 pub struct ImperialEventsTheDayOfTheEmperorsEnthronement;
+impl ImperialEventsTheDayOfTheEmperorsEnthronement {
+    const PERIODS: &'static [Period] = &[Period::once(&HEISEI_30_LAW_99, ymd(2019, 5, 1))];
+}
 impl PublicHoliday for ImperialEventsTheDayOfTheEmperorsEnthronement {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "天皇の即位の日".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheDayOfTheEmperorsEnthronement
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
 }
 
 /// 即位礼正殿の儀
 pub struct ImperialEventsTheEnthronementCeremony;
+impl ImperialEventsTheEnthronementCeremony {
+    const PERIODS: &'static [Period] = &[Period::once(&HEISEI_30_LAW_99, ymd(2019, 10, 22))];
+}
 impl PublicHoliday for ImperialEventsTheEnthronementCeremony {
     fn is_holiday(&self, date: &NaiveDate) -> bool {
-        *date == NaiveDate::from_ymd_opt(2019, 10, 22).unwrap()
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
     }
-
     fn name(&self) -> String {
         "即位礼正殿の儀".to_string()
     }
+    fn id(&self) -> HolidayId {
+        HolidayId::ImperialEventsTheEnthronementCeremony
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::ImperialEvent
+    }
+}
+
+/// 四方拝(1927年から1948年まで)
+pub struct Shihohai;
+impl Shihohai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_2_ORDINANCE_25,
+            ymd(1927, 3, 3),
+            DateRule::Fixed { month: 1, day: 1 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for Shihohai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "四方拝".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::Shihohai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
 }
 
-/// 振替休日を取得する
+/// 元始祭(1873年から1948年まで)
+pub struct GenshiSai;
+impl GenshiSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 1, day: 3 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for GenshiSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "元始祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::GenshiSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 新年宴会(1873年から1948年まで)
+pub struct ShinnenEnkai;
+impl ShinnenEnkai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 1, day: 5 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for ShinnenEnkai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "新年宴会".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::ShinnenEnkai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 孝明天皇祭(1873年から1912年まで)
+pub struct KomeiTennoSai;
+impl KomeiTennoSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 1, day: 30 },
+        ),
+        Period::abolished(&TAISHO_1_ORDINANCE_19, ymd(1912, 9, 3)),
+    ];
+}
+impl PublicHoliday for KomeiTennoSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "孝明天皇祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::KomeiTennoSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 紀元節(1873年から1948年まで)
+pub struct KigenSetsu;
+impl KigenSetsu {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 2, day: 11 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for KigenSetsu {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "紀元節".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::KigenSetsu
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 春季皇霊祭(1879年から1948年まで)
+pub struct ShunkiKoreiSai;
+impl ShunkiKoreiSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_11_DECREE_23,
+            ymd(1878, 6, 5),
+            DateRule::VernalEquinox,
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for ShunkiKoreiSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "春季皇霊祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::ShunkiKoreiSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        vernal_equinox_status(date.year(), &NaiveDate::MAX)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        vernal_equinox_status(date.year(), known_on)
+    }
+}
+
+/// 神武天皇祭(1873年から1948年まで)
+pub struct JimmuTennoSai;
+impl JimmuTennoSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 4, day: 3 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for JimmuTennoSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "神武天皇祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::JimmuTennoSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 明治天皇祭(1913年から1926年まで)
+pub struct MeijiTennoSai;
+impl MeijiTennoSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &TAISHO_1_ORDINANCE_19,
+            ymd(1912, 9, 3),
+            DateRule::Fixed { month: 7, day: 30 },
+        ),
+        Period::abolished(&SHOWA_2_ORDINANCE_25, ymd(1927, 3, 3)),
+    ];
+}
+impl PublicHoliday for MeijiTennoSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "明治天皇祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::MeijiTennoSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 天長節
+pub struct TenchoSetsu;
+impl TenchoSetsu {
+    const PERIODS: &'static [Period] = &[
+        // 明治天皇: 11月3日
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 11, day: 3 },
+        ),
+        // 大正天皇: 8月31日
+        Period::since(
+            &TAISHO_1_ORDINANCE_19,
+            ymd(1912, 9, 3),
+            DateRule::Fixed { month: 8, day: 31 },
+        ),
+        // 昭和天皇: 4月29日
+        Period::since(
+            &SHOWA_2_ORDINANCE_25,
+            ymd(1927, 3, 3),
+            DateRule::Fixed { month: 4, day: 29 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for TenchoSetsu {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "天長節".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::TenchoSetsu
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 天長節祝日(1913年から1926年まで)
+pub struct TenchoSetsuShukujitsu;
+impl TenchoSetsuShukujitsu {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &TAISHO_2_ORDINANCE_259,
+            ymd(1913, 7, 16),
+            DateRule::Fixed { month: 10, day: 31 },
+        ),
+        Period::abolished(&SHOWA_2_ORDINANCE_25, ymd(1927, 3, 3)),
+    ];
+}
+impl PublicHoliday for TenchoSetsuShukujitsu {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "天長節祝日".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::TenchoSetsuShukujitsu
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 秋季皇霊祭(1878年から1947年まで)
+pub struct ShukiKoreiSai;
+impl ShukiKoreiSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_11_DECREE_23,
+            ymd(1878, 6, 5),
+            DateRule::AutumnalEquinox,
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for ShukiKoreiSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "秋季皇霊祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::ShukiKoreiSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        autumnal_equinox_status(date.year(), &NaiveDate::MAX)
+    }
+    fn status_as_known_on(&self, date: &NaiveDate, known_on: &NaiveDate) -> HolidayStatus {
+        autumnal_equinox_status(date.year(), known_on)
+    }
+}
+
+/// 神嘗祭(1873年から1947年まで)
+pub struct KannameSai;
+impl KannameSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 9, day: 17 },
+        ),
+        Period::since(
+            &MEIJI_12_DECREE_27,
+            ymd(1879, 7, 5),
+            DateRule::Fixed { month: 10, day: 17 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for KannameSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "神嘗祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::KannameSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 明治節(1927年から1947年まで)
+pub struct MeijiSetsu;
+impl MeijiSetsu {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_2_ORDINANCE_25,
+            ymd(1927, 3, 3),
+            DateRule::Fixed { month: 11, day: 3 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for MeijiSetsu {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "明治節".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::MeijiSetsu
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 新嘗祭(1873年から1947年まで)
+pub struct NiinameSai;
+impl NiinameSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &MEIJI_6_DECREE_344,
+            ymd(1873, 10, 14),
+            DateRule::Fixed { month: 11, day: 23 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for NiinameSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "新嘗祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::NiinameSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 大正天皇祭(1927年から1947年まで)
+pub struct TaishoTennoSai;
+impl TaishoTennoSai {
+    const PERIODS: &'static [Period] = &[
+        Period::since(
+            &SHOWA_2_ORDINANCE_25,
+            ymd(1927, 3, 3),
+            DateRule::Fixed { month: 12, day: 25 },
+        ),
+        Period::abolished(&SHOWA_23_LAW_178, ymd(1948, 7, 20)),
+    ];
+}
+impl PublicHoliday for TaishoTennoSai {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        is_holiday_in(Self::PERIODS, date)
+    }
+    fn periods(&self) -> &'static [Period] {
+        Self::PERIODS
+    }
+    fn name(&self) -> String {
+        "大正天皇祭".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::TaishoTennoSai
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::FestivalDay
+    }
+}
+
+/// 振替休日の規則の期間
+pub const SUBSTITUTE_HOLIDAY_PERIODS: &[Period] = &[
+    Period::since(
+        &SHOWA_48_LAW_10,
+        ymd(1973, 4, 12),
+        DateRule::SubstituteNextDay,
+    ),
+    Period::since(
+        &HEISEI_17_LAW_43,
+        ymd(2007, 1, 1),
+        DateRule::SubstituteNextNonHoliday,
+    ),
+];
+
+/// 国民の休日の規則の期間
+pub const CITIZENS_HOLIDAY_PERIODS: &[Period] = &[
+    Period::since(
+        &SHOWA_60_LAW_103,
+        ymd(1985, 12, 27),
+        DateRule::BetweenHolidaysExceptSunday,
+    ),
+    Period::since(
+        &HEISEI_17_LAW_43,
+        ymd(2007, 1, 1),
+        DateRule::BetweenHolidays,
+    ),
+];
+
+/// 国民の休日
+///
+/// 前日と翌日が国民の祝日である日です. [`HOLIDAYS`] の国民の祝日から判定します.
+/// `HolidayCalendar` では, カレンダーの規則から判定します.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::public_holiday::{CitizensHoliday, PublicHoliday};
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 9, 22).unwrap();
+/// assert!(CitizensHoliday.is_holiday(&date));
+/// ```
+pub struct CitizensHoliday;
+impl PublicHoliday for CitizensHoliday {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        find_citizens_holiday(date, &NaiveDate::MAX, national_holiday_on).is_some()
+    }
+    fn name(&self) -> String {
+        "国民の休日".to_string()
+    }
+    fn id(&self) -> HolidayId {
+        HolidayId::CitizensHoliday
+    }
+    fn kind(&self) -> HolidayKind {
+        HolidayKind::CitizensHoliday
+    }
+    fn status(&self, date: &NaiveDate) -> HolidayStatus {
+        find_citizens_holiday(date, &NaiveDate::MAX, national_holiday_on)
+            .unwrap_or(HolidayStatus::Statutory)
+    }
+    fn periods(&self) -> &'static [Period] {
+        CITIZENS_HOLIDAY_PERIODS
+    }
+}
+
+/// [`HOLIDAYS`] のうち, 1948年以前の祝祭日を除いた指定した日付の祝日を取得する
+pub(crate) fn national_holiday_on(date: &NaiveDate) -> Option<&'static dyn PublicHoliday> {
+    HOLIDAYS
+        .iter()
+        .copied()
+        .filter(|holiday| holiday.kind() != HolidayKind::FestivalDay)
+        .find(|holiday| holiday.is_holiday(date))
+}
+
+/// 振替休日を判定し, 振替元となった祝日を取得する
 ///
 /// # Arguments
 ///
@@ -456,33 +1470,100 @@ impl PublicHoliday for ImperialEventsTheEnthronementCeremony {
 ///
 /// # Returns
 ///
-/// 振替休日の名前
-pub fn substitute_holiday(date: &NaiveDate) -> Option<String> {
-    // 1973年(昭和48年)4月12日 - 改正・施行
-    if date.year() < 1973 {
-        return None;
-    }
+/// 振替休日の場合は振替元の祝日
+pub fn substitute_holiday(date: &NaiveDate) -> Option<&'static dyn PublicHoliday> {
+    find_substitute_holiday(date, &NaiveDate::MAX, national_holiday_on).map(|(holiday, _)| holiday)
+}
+
+/// 指定した祝日の判定関数を使って振替休日を判定し, 振替元となった祝日を取得する
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `known_on` - 法令を参照する時点
+/// * `holiday_on` - 指定した日付の祝日を返す関数
+///
+/// # Returns
+///
+/// 振替休日の場合は振替元の祝日と, 振替元から前日までの祝日のうち最も不確かな確からしさ
+pub(crate) fn find_substitute_holiday<'a, F>(
+    date: &NaiveDate,
+    known_on: &NaiveDate,
+    holiday_on: F,
+) -> Option<(&'a dyn PublicHoliday, HolidayStatus)>
+where
+    F: Fn(&NaiveDate) -> Option<&'a dyn PublicHoliday>,
+{
+    // 1973年(昭和48年)4月12日 - 公布・施行
+    let rule = period_as_known_on(SUBSTITUTE_HOLIDAY_PERIODS, date, known_on)?.rule?;
 
     // 日曜日に振替休日は存在しない
     if date.weekday().number_from_monday() == 7 {
         return None;
     }
 
-    let mut current_date = *date - chrono::Duration::days(1);
-    loop {
-        let mut filtered_holiday = HOLIDAYS
-            .iter()
-            .filter(|holiday| holiday.is_holiday(&current_date));
-        if filtered_holiday.clone().count() == 0 {
+    let mut current_date = date.pred_opt()?;
+    // 2006年(平成18年)までは日曜日に当たる祝日の翌日のみ
+    if rule == DateRule::SubstituteNextDay {
+        if current_date.weekday().number_from_monday() != 7 {
             return None;
         }
+        let holiday = holiday_on(&current_date)?;
+        return Some((holiday, holiday.status_as_known_on(&current_date, known_on)));
+    }
+
+    let mut status = HolidayStatus::Statutory;
+    loop {
+        let holiday = holiday_on(&current_date)?;
+        status = status.max(holiday.status_as_known_on(&current_date, known_on));
         if current_date.weekday().number_from_monday() == 7 {
-            let name = format!("{} 振替休日", filtered_holiday.next().unwrap().name());
-            return Some(name);
+            return Some((holiday, status));
         }
 
-        current_date -= chrono::Duration::days(1);
+        current_date = current_date.pred_opt()?;
+    }
+}
+
+/// 指定した祝日の判定関数を使って国民の休日を判定する
+///
+/// # Arguments
+///
+/// * `date` - 日付
+/// * `known_on` - 法令を参照する時点
+/// * `holiday_on` - 指定した日付の国民の祝日を返す関数
+///
+/// # Returns
+///
+/// 国民の休日の場合は前日と翌日の祝日のうち不確かな方の確からしさ
+pub(crate) fn find_citizens_holiday<'a, F>(
+    date: &NaiveDate,
+    known_on: &NaiveDate,
+    holiday_on: F,
+) -> Option<HolidayStatus>
+where
+    F: Fn(&NaiveDate) -> Option<&'a dyn PublicHoliday>,
+{
+    // 1985年(昭和60年)12月27日 - 公布・施行
+    let rule = period_as_known_on(CITIZENS_HOLIDAY_PERIODS, date, known_on)?.rule?;
+
+    // 国民の祝日は国民の休日ではない
+    if holiday_on(date).is_some() {
+        return None;
     }
+    // 2006年(平成18年)までは日曜日と振替休日を除く
+    if rule == DateRule::BetweenHolidaysExceptSunday
+        && (date.weekday().number_from_monday() == 7
+            || find_substitute_holiday(date, known_on, &holiday_on).is_some())
+    {
+        return None;
+    }
+
+    // 前日と翌日が国民の祝日の場合
+    let prev_day = date.pred_opt()?;
+    let next_day = date.succ_opt()?;
+    let prev = holiday_on(&prev_day)?.status_as_known_on(&prev_day, known_on);
+    let next = holiday_on(&next_day)?.status_as_known_on(&next_day, known_on);
+    Some(prev.max(next))
 }
 
 /// 指定した週の指定した曜日の日付を取得する
@@ -493,11 +1574,21 @@ pub fn substitute_holiday(date: &NaiveDate) -> Option<String> {
 ///
 /// * `date` - 日付
 /// * `week` - 週
+/// * `weekday` - 曜日(月曜日が1)
 ///
 /// # Returns
 ///
 /// 指定した週の指定した曜日の日付
-fn week_day(date: &NaiveDate, week: u32, weekday: u32) -> Option<NaiveDate> {
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use yasumi::public_holiday::week_day;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// assert_eq!(week_day(&date, 2, 1), NaiveDate::from_ymd_opt(2024, 1, 8));
+/// ```
+pub fn week_day(date: &NaiveDate, week: u32, weekday: u32) -> Option<NaiveDate> {
     if !(1..=5).contains(&week) {
         return None;
     }
@@ -541,4 +1632,101 @@ mod tests {
             Some(NaiveDate::from_ymd_opt(2024, 9, 20).unwrap())
         );
     }
+
+    #[test]
+    fn test_rules_before_enforcement() {
+        // 1948年7月20日の施行前は国民の祝日は存在しない
+        let date = NaiveDate::from_ymd_opt(1948, 5, 3).unwrap();
+        assert!(!ConstitutionMemorialDay.is_holiday(&date));
+        let date = NaiveDate::from_ymd_opt(1500, 1, 1).unwrap();
+        assert!(!NewYearsDay.is_holiday(&date));
+        let date = NaiveDate::from_ymd_opt(1948, 9, 23).unwrap();
+        assert!(AutumnalEquinoxDay.is_holiday(&date));
+    }
+
+    #[test]
+    fn test_substitute_holiday_rules() {
+        // 日曜日と月曜日が続けて祝日の場合
+        let holiday_on = |date: &NaiveDate| -> Option<&dyn PublicHoliday> {
+            matches!(date.day(), 1 | 2).then_some(&NewYearsDay)
+        };
+        // 2006年1月1日(日)は翌日が祝日のため振り替えられない
+        let date = NaiveDate::from_ymd_opt(2006, 1, 3).unwrap();
+        assert!(find_substitute_holiday(&date, &NaiveDate::MAX, holiday_on).is_none());
+        // 2012年1月1日(日)は祝日でない最も近い日に振り替えられる
+        let date = NaiveDate::from_ymd_opt(2012, 1, 3).unwrap();
+        assert!(find_substitute_holiday(&date, &NaiveDate::MAX, holiday_on).is_some());
+        // 2007年の改正の公布前は翌日のみ
+        let known_on = NaiveDate::from_ymd_opt(2005, 5, 19).unwrap();
+        assert!(find_substitute_holiday(&date, &known_on, holiday_on).is_none());
+    }
+
+    #[test]
+    fn test_citizens_holiday_rules() {
+        // 土曜日と月曜日が祝日の場合
+        let holiday_on = |date: &NaiveDate| -> Option<&dyn PublicHoliday> {
+            matches!(date.day(), 1 | 3).then_some(&NewYearsDay)
+        };
+        // 2006年までは日曜日を除く
+        let date = NaiveDate::from_ymd_opt(2006, 4, 2).unwrap();
+        assert_eq!(
+            find_citizens_holiday(&date, &NaiveDate::MAX, holiday_on),
+            None
+        );
+        let date = NaiveDate::from_ymd_opt(2012, 4, 2).unwrap();
+        assert_eq!(
+            find_citizens_holiday(&date, &NaiveDate::MAX, holiday_on),
+            Some(HolidayStatus::Statutory)
+        );
+        // 1985年12月27日の施行前は存在しない
+        let date = NaiveDate::from_ymd_opt(1985, 12, 2).unwrap();
+        assert_eq!(
+            find_citizens_holiday(&date, &NaiveDate::MAX, holiday_on),
+            None
+        );
+
+        let date = NaiveDate::from_ymd_opt(2015, 9, 22).unwrap();
+        assert!(CitizensHoliday.is_holiday(&date));
+        assert!(!CitizensHoliday.is_holiday(&date.succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn test_equinox_day_range() {
+        use crate::equinox::{autumnal_equinox_day, vernal_equinox_day};
+
+        // 3001年以降も近似値で春分の日・秋分の日を判定する
+        for year in [3000, 3001, 4000] {
+            let march = NaiveDate::from_ymd_opt(year, 3, 1).unwrap();
+            let vernal: Vec<_> = march
+                .iter_days()
+                .take(31)
+                .filter(|date| VernalEquinoxDay.is_holiday(date))
+                .collect();
+            assert_eq!(
+                vernal,
+                [NaiveDate::from_ymd_opt(year, 3, vernal_equinox_day(year).unwrap()).unwrap()]
+            );
+            let september = NaiveDate::from_ymd_opt(year, 9, 1).unwrap();
+            let autumnal: Vec<_> = september
+                .iter_days()
+                .take(30)
+                .filter(|date| AutumnalEquinoxDay.is_holiday(date))
+                .collect();
+            assert_eq!(
+                autumnal,
+                [NaiveDate::from_ymd_opt(year, 9, autumnal_equinox_day(year).unwrap()).unwrap()]
+            );
+            assert_eq!(
+                VernalEquinoxDay.status(&vernal[0]),
+                HolidayStatus::Projected
+            );
+        }
+
+        // 4001年以降は春分日・秋分日を求めない
+        let march = NaiveDate::from_ymd_opt(4001, 3, 1).unwrap();
+        assert!(march
+            .iter_days()
+            .take(31)
+            .all(|date| !VernalEquinoxDay.is_holiday(&date)));
+    }
 }
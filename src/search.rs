@@ -0,0 +1,202 @@
+//! 前後の祝日の検索
+//!
+//! 指定した日付の前後にある祝日を探す関数を定義します.
+
+use std::ops::Bound;
+
+use chrono::NaiveDate;
+
+use crate::calendar::HolidayCalendar;
+use crate::datelike::DateLike;
+
+/// 前後の祝日を探すときに, 指定した日付自体を対象に含めるかどうか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inclusion {
+    /// 指定した日付が祝日の場合はその日を返す
+    Inclusive,
+    /// 指定した日付を含めない
+    Exclusive,
+}
+
+impl Inclusion {
+    fn bound(self, date: NaiveDate) -> Bound<NaiveDate> {
+        match self {
+            Inclusion::Inclusive => Bound::Included(date),
+            Inclusion::Exclusive => Bound::Excluded(date),
+        }
+    }
+}
+
+impl HolidayCalendar {
+    /// 指定した日付以降で最初の祝日を取得します.
+    ///
+    /// 祝日は年単位でまとめて求めるため, 1日ずつ判定することはありません.
+    /// 規則が祝日になりうる範囲(`PublicHoliday::possible_dates`)の外は探しません.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `inclusion` - 指定した日付自体を対象に含めるかどうか
+    ///
+    /// # Returns
+    ///
+    /// 祝日の日付と名前を返します. 日付が不正な場合や祝日が存在しない場合は `None` を返します.
+    pub fn next_holiday<T: DateLike>(
+        &self,
+        date: T,
+        inclusion: Inclusion,
+    ) -> Option<(NaiveDate, String)> {
+        let date = date.date()?;
+        self.holiday_iter((inclusion.bound(date), Bound::Unbounded))
+            .next()
+            .map(|holiday| (holiday.date, holiday.name))
+    }
+
+    /// 指定した日付以前で最後の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    /// * `inclusion` - 指定した日付自体を対象に含めるかどうか
+    ///
+    /// # Returns
+    ///
+    /// 祝日の日付と名前を返します. 日付が不正な場合や祝日が存在しない場合は `None` を返します.
+    pub fn previous_holiday<T: DateLike>(
+        &self,
+        date: T,
+        inclusion: Inclusion,
+    ) -> Option<(NaiveDate, String)> {
+        let date = date.date()?;
+        self.holiday_iter((Bound::Unbounded, inclusion.bound(date)))
+            .next_back()
+            .map(|holiday| (holiday.date, holiday.name))
+    }
+
+    /// 指定した日付より後で `n` 番目の祝日を取得します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付(含まない)
+    /// * `n` - 何番目か. `1` の場合は `next_holiday(date, Inclusion::Exclusive)` と同じです
+    ///
+    /// # Returns
+    ///
+    /// 祝日の日付と名前を返します. 日付が不正な場合, `n` が `0` の場合や祝日が存在しない場合は `None` を返します.
+    pub fn nth_holiday_after<T: DateLike>(&self, date: T, n: usize) -> Option<(NaiveDate, String)> {
+        let date = date.date()?;
+        self.holiday_iter((Bound::Excluded(date), Bound::Unbounded))
+            .nth(n.checked_sub(1)?)
+            .map(|holiday| (holiday.date, holiday.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rstest::rstest;
+
+    /// 日付の文字列と祝日名の組を, 検索結果と比べられる形に変換します.
+    fn holiday(expected: Option<(&str, &str)>) -> Option<(NaiveDate, String)> {
+        expected.map(|(date, name)| {
+            let date = NaiveDate::parse_from_str(date, "%Y/%m/%d").unwrap();
+            (date, name.to_string())
+        })
+    }
+
+    #[rstest]
+    #[case("2024/09/16", Inclusion::Inclusive, Some(("2024/09/16", "敬老の日")))]
+    #[case("2024/09/16", Inclusion::Exclusive, Some(("2024/09/22", "秋分の日")))]
+    #[case("2024/12/24", Inclusion::Exclusive, Some(("2025/01/01", "元日")))]
+    #[case("2019/04/29", Inclusion::Exclusive, Some(("2019/04/30", "国民の休日")))]
+    #[case("2024/13/01", Inclusion::Inclusive, None)]
+    fn test_next_holiday(
+        #[case] date: &str,
+        #[case] inclusion: Inclusion,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(calendar.next_holiday(date, inclusion), holiday(expected));
+    }
+
+    #[rstest]
+    #[case("2024/09/22", Inclusion::Inclusive, Some(("2024/09/22", "秋分の日")))]
+    #[case("2024/09/22", Inclusion::Exclusive, Some(("2024/09/16", "敬老の日")))]
+    #[case("2025/01/01", Inclusion::Exclusive, Some(("2024/11/23", "勤労感謝の日")))]
+    #[case("1873/11/03", Inclusion::Inclusive, Some(("1873/11/03", "天長節")))]
+    #[case("1873/11/03", Inclusion::Exclusive, None)]
+    fn test_previous_holiday(
+        #[case] date: &str,
+        #[case] inclusion: Inclusion,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(
+            calendar.previous_holiday(date, inclusion),
+            holiday(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("2024/04/28", 0, None)]
+    #[case("2024/04/28", 1, Some(("2024/04/29", "昭和の日")))]
+    #[case("2024/04/28", 4, Some(("2024/05/05", "こどもの日")))]
+    #[case("2024/04/28", 5, Some(("2024/05/06", "こどもの日 振替休日")))]
+    #[case("2024/12/01", 2, Some(("2025/01/13", "成人の日")))]
+    fn test_nth_holiday_after(
+        #[case] date: &str,
+        #[case] n: usize,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        let calendar = HolidayCalendar::national();
+        assert_eq!(calendar.nth_holiday_after(date, n), holiday(expected));
+    }
+
+    #[test]
+    fn test_custom_calendar() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let calendar = HolidayCalendar::empty_builder()
+            .date("opening", "開業記念日", date(2030, 1, 1))
+            .date("closing", "閉業日", date(2031, 3, 31))
+            .build();
+        let opening = Some((date(2030, 1, 1), "開業記念日".to_string()));
+        let closing = Some((date(2031, 3, 31), "閉業日".to_string()));
+
+        assert_eq!(
+            calendar.next_holiday(date(1800, 1, 1), Inclusion::Exclusive),
+            opening
+        );
+        assert_eq!(
+            calendar.next_holiday(date(2030, 1, 1), Inclusion::Exclusive),
+            closing
+        );
+        assert_eq!(
+            calendar.next_holiday(date(2031, 3, 31), Inclusion::Exclusive),
+            None
+        );
+        assert_eq!(
+            calendar.previous_holiday(date(2200, 1, 1), Inclusion::Exclusive),
+            closing
+        );
+        assert_eq!(
+            calendar.previous_holiday(date(2030, 1, 1), Inclusion::Exclusive),
+            None
+        );
+        assert_eq!(
+            calendar.previous_holiday(date(1800, 1, 1), Inclusion::Inclusive),
+            None
+        );
+        assert_eq!(calendar.nth_holiday_after(date(1800, 1, 1), 2), closing);
+        assert_eq!(calendar.nth_holiday_after(date(1800, 1, 1), 3), None);
+
+        // 国民の祝日に独自の休日を加えたカレンダー
+        let calendar = HolidayCalendar::builder()
+            .date("opening", "開業記念日", date(2030, 1, 1))
+            .build();
+        assert_eq!(
+            calendar.previous_holiday(date(1800, 1, 1), Inclusion::Exclusive),
+            None
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! ビルド時に生成した祝日の表
+//!
+//! `static-table` フィーチャーが有効な場合, 対応範囲の国民の祝日をビルド時に求めて埋め込みます.
+//! 国民の祝日のカレンダーは, 実行時に祝日の規則を評価せずにこの表を参照します.
+
+use chrono::NaiveDate;
+
+use crate::holiday::{Holiday, HolidayId, HolidayKind, HolidayStatus};
+use crate::public_holiday::{CitizensHoliday, PublicHoliday, HOLIDAYS, SUBSTITUTE_HOLIDAY_PERIODS};
+
+/// 祝日の種類と, 該当した規則
+enum Rule {
+    /// `HOLIDAYS` の指定した位置の規則
+    Holiday(usize),
+    /// `HOLIDAYS` の指定した位置の規則の振替休日
+    Substitute(usize),
+    /// 国民の休日
+    Citizens,
+}
+
+/// 祝日の表の要素
+struct Entry {
+    /// 1月1日を1とする通算日
+    ordinal: u16,
+    rule: Rule,
+    status: HolidayStatus,
+    /// 根拠となった期間の位置
+    period: usize,
+}
+
+impl Entry {
+    fn holiday(&self, year: i32) -> Option<Holiday> {
+        let date = NaiveDate::from_yo_opt(year, self.ordinal as u32)?;
+        let holiday = match self.rule {
+            Rule::Holiday(index) => {
+                let rule = HOLIDAYS[index];
+                Holiday {
+                    date,
+                    id: rule.id(),
+                    name: rule.name(),
+                    kind: rule.kind(),
+                    substitute_for: None,
+                    status: self.status,
+                    legal_basis: rule.periods().get(self.period),
+                }
+            }
+            Rule::Substitute(index) => {
+                let rule = HOLIDAYS[index];
+                Holiday {
+                    date,
+                    id: HolidayId::SubstituteHoliday,
+                    name: format!("{} 振替休日", rule.name()),
+                    kind: HolidayKind::Substitute,
+                    substitute_for: Some(rule.id()),
+                    status: self.status,
+                    legal_basis: SUBSTITUTE_HOLIDAY_PERIODS.get(self.period),
+                }
+            }
+            Rule::Citizens => Holiday {
+                date,
+                id: CitizensHoliday.id(),
+                name: CitizensHoliday.name(),
+                kind: CitizensHoliday.kind(),
+                substitute_for: None,
+                status: self.status,
+                legal_basis: CitizensHoliday.periods().get(self.period),
+            },
+        };
+        Some(holiday)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/static_table.rs"));
+
+/// 表から指定した年の祝日を取得します. 表にない年は `None` を返します.
+pub(crate) fn year_holidays(year: i32) -> Option<Vec<Holiday>> {
+    let index = usize::try_from(year.checked_sub(FIRST_YEAR)?).ok()?;
+    let start = *YEAR_OFFSETS.get(index)? as usize;
+    let end = *YEAR_OFFSETS.get(index + 1)? as usize;
+    ENTRIES[start..end]
+        .iter()
+        .map(|entry| entry.holiday(year))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::public_holiday::supported_years;
+    use crate::HolidayCalendar;
+
+    #[test]
+    fn test_matches_rules() {
+        // ビルダーから作成したカレンダーは表を使わずに規則を評価する
+        let calendar = HolidayCalendar::builder().build();
+        for year in supported_years() {
+            assert_eq!(
+                year_holidays(year),
+                Some(calendar.year_holidays_detail(year)),
+                "{year}"
+            );
+        }
+        assert_eq!(year_holidays(supported_years().start() - 1), None);
+        assert_eq!(year_holidays(supported_years().end() + 1), None);
+    }
+}
@@ -0,0 +1,116 @@
+//! 定休日とする曜日
+//!
+//! カレンダーごとに休日として扱う曜日の集合(`Weekend`)を定義します.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// 定休日とする曜日の集合
+///
+/// `is_no_workday` や営業日の計算で休日として扱う曜日を表します.
+/// 既定値は土曜日と日曜日です.
+///
+/// # Examples
+/// ```
+/// use chrono::Weekday;
+/// use yasumi::Weekend;
+///
+/// // 日曜日と水曜日が定休日
+/// let weekend = Weekend::new(&[Weekday::Sun, Weekday::Wed]);
+/// assert!(weekend.contains(Weekday::Wed));
+/// assert!(!weekend.contains(Weekday::Sat));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend(u8);
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::SATURDAY_AND_SUNDAY
+    }
+}
+
+impl Weekend {
+    /// 土曜日と日曜日
+    pub const SATURDAY_AND_SUNDAY: Weekend =
+        Weekend(1 << Weekday::Sat as u8 | 1 << Weekday::Sun as u8);
+    /// 日曜日のみ
+    pub const SUNDAY: Weekend = Weekend(1 << Weekday::Sun as u8);
+    /// 定休日なし
+    pub const NONE: Weekend = Weekend(0);
+
+    /// 指定した曜日を定休日とします.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekdays` - 定休日とする曜日
+    pub fn new(weekdays: &[Weekday]) -> Weekend {
+        weekdays
+            .iter()
+            .fold(Weekend::NONE, |weekend, weekday| weekend.with(*weekday))
+    }
+
+    /// 指定した曜日を追加した定休日を返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday` - 追加する曜日
+    pub const fn with(self, weekday: Weekday) -> Weekend {
+        Weekend(self.0 | 1 << weekday as u8)
+    }
+
+    /// 指定した曜日を除いた定休日を返します.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday` - 除く曜日
+    pub const fn without(self, weekday: Weekday) -> Weekend {
+        Weekend(self.0 & !(1 << weekday as u8))
+    }
+
+    /// 指定した曜日が定休日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday` - 曜日
+    pub const fn contains(&self, weekday: Weekday) -> bool {
+        self.0 & 1 << weekday as u8 != 0
+    }
+
+    /// 指定した日付が定休日かどうかを判定します.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - 日付
+    pub fn is_weekend(&self, date: &NaiveDate) -> bool {
+        self.contains(date.weekday())
+    }
+
+    /// 1週間のうち定休日の日数を返します.
+    pub const fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// 定休日がないかどうかを判定します.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_weekend() {
+        let weekend = Weekend::default();
+        assert!(weekend.contains(Weekday::Sat));
+        assert!(weekend.contains(Weekday::Sun));
+        assert!(!weekend.contains(Weekday::Mon));
+        assert_eq!(weekend.len(), 2);
+
+        let weekend = weekend.without(Weekday::Sat).with(Weekday::Wed);
+        assert_eq!(weekend, Weekend::new(&[Weekday::Wed, Weekday::Sun]));
+        assert_eq!(Weekend::new(&[]), Weekend::NONE);
+        assert!(Weekend::NONE.is_empty());
+    }
+}
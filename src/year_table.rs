@@ -0,0 +1,82 @@
+//! 1年分の祝日の表
+//!
+//! 祝日を年単位でまとめて求めておき, 日付から祝日を定数時間で引けるようにします.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::holiday::Holiday;
+
+/// 1年分の祝日の表
+pub(crate) struct YearTable {
+    /// 祝日である日のビット. 1月1日を0番目とする
+    mask: [u64; 6],
+    /// 祝日(昇順)
+    holidays: Vec<Holiday>,
+}
+
+impl YearTable {
+    /// 1年分の祝日(昇順, 1日に1件)から表を作成します.
+    pub(crate) fn new(holidays: Vec<Holiday>) -> YearTable {
+        let mut mask = [0; 6];
+        for holiday in &holidays {
+            let (word, bit) = position(&holiday.date);
+            mask[word] |= 1 << bit;
+        }
+        YearTable { mask, holidays }
+    }
+
+    /// 指定した日付が祝日かどうかを判定します.
+    pub(crate) fn contains(&self, date: &NaiveDate) -> bool {
+        let (word, bit) = position(date);
+        self.mask[word] >> bit & 1 == 1
+    }
+
+    /// 指定した日付の祝日を取得します.
+    ///
+    /// 祝日の位置は, その日より前のビットの数から求めます.
+    pub(crate) fn get(&self, date: &NaiveDate) -> Option<&Holiday> {
+        if !self.contains(date) {
+            return None;
+        }
+        let (word, bit) = position(date);
+        let rank = self.mask[..word]
+            .iter()
+            .map(|bits| bits.count_ones())
+            .sum::<u32>()
+            + (self.mask[word] & ((1 << bit) - 1)).count_ones();
+        self.holidays.get(rank as usize)
+    }
+
+    /// 祝日の一覧(昇順)を取得します.
+    pub(crate) fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+}
+
+/// 日付のビットの位置(語, ビット)
+fn position(date: &NaiveDate) -> (usize, u32) {
+    let ordinal = date.ordinal0();
+    ((ordinal / 64) as usize, ordinal % 64)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::HolidayCalendar;
+
+    #[test]
+    fn test_get() {
+        let calendar = HolidayCalendar::national();
+        let table = YearTable::new(calendar.year_holidays_detail(2024));
+        for date in NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .iter_days()
+            .take(366)
+        {
+            assert_eq!(table.get(&date), calendar.holiday_detail(date).as_ref());
+            assert_eq!(table.contains(&date), calendar.is_holiday(date));
+        }
+        assert_eq!(table.holidays().len(), 21);
+    }
+}